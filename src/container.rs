@@ -1,11 +1,17 @@
 #[cfg(feature = "doc")]
 use crate::{scope, scope_mut, scope_val};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
 use {
-    crate::{particle::*, proof::*, traits::*},
+    crate::{particle::*, proof::*, traits::*, Character, EditPlan},
     core::{
-        convert::{AsMut, AsRef},
-        fmt, mem, ops,
+        cmp,
+        convert::{AsMut, AsRef, TryFrom},
+        fmt,
+        hash::{Hash, Hasher},
+        iter, mem, ops,
     },
+    debug_unreachable::debug_unreachable,
 };
 
 /// A branded container, that allows access only to indices and ranges with
@@ -73,7 +79,8 @@ where
     ///
     /// Any indices of the array cannot be invalidated. i.e., variable size
     /// collections such as `Vec` and `String` can be grown or modified, but
-    /// cannot remove any elements.
+    /// cannot remove any elements. To grow a container safely, use
+    /// [`push`](`Container::push`).
     pub unsafe fn untrusted_mut(&mut self) -> &mut Array
     {
         &mut self.array
@@ -124,11 +131,99 @@ where
         self.array
     }
 
+    /// The whole container as a slice, as `&self[..]`.
+    pub fn as_slice(&self) -> &Array::Slice {
+        &self[..]
+    }
+
+    /// The whole container as a mutable slice, as `&mut self[..]`.
+    pub fn as_slice_mut(&mut self) -> &mut Array::Slice
+    where
+        Array: TrustedContainerMut,
+    {
+        &mut self[..]
+    }
+
+    /// The slice of the container covered by `range`, as `&self[range]`.
+    pub fn slice<P>(&self, range: perfect::Range<'id, P>) -> &Array::Slice {
+        &self[range]
+    }
+
+    /// The mutable slice of the container covered by `range`, as `&mut self[range]`.
+    pub fn slice_mut<P>(&mut self, range: perfect::Range<'id, P>) -> &mut Array::Slice
+    where
+        Array: TrustedContainerMut,
+    {
+        &mut self[range]
+    }
+
+    /// The slice of `len` units starting at `start`,
+    /// or `None` if it does not end in bounds on an item boundary.
+    pub fn chunk<P>(&self, start: perfect::Index<'id, P>, len: u32) -> Option<&Array::Slice> {
+        let end = self.vet_or_end(start.untrusted().checked_add(len)?).ok()?;
+        let range = unsafe {
+            perfect::Range::<Unknown>::new(start.untrusted(), end.untrusted(), self.id())
+        };
+        Some(&self[range])
+    }
+
+    /// An owned copy of the slice covered by `range`, as `self[range].to_owned()`,
+    /// e.g. a `String` for a range of a `str` container.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn to_owned_range<P>(
+        &self,
+        range: perfect::Range<'id, P>,
+    ) -> <Array::Slice as ToOwned>::Owned
+    where
+        Array::Slice: ToOwned,
+    {
+        self[range].to_owned()
+    }
+
+    /// The item at `ix`, or `Err(OutOfBounds)` if `ix` is the end index.
+    pub fn try_index<P>(&self, ix: perfect::Index<'id, P>) -> Result<&Array::Item, IndexError> {
+        Ok(&self[self.vet(ix.erased())?])
+    }
+
+    /// The items at `a` and `b`, as `(&self[a], &self[b])`.
+    pub fn get_pair(
+        &self,
+        a: perfect::Index<'id, NonEmpty>,
+        b: perfect::Index<'id, NonEmpty>,
+    ) -> (&Array::Item, &Array::Item) {
+        (&self[a], &self[b])
+    }
+
     /// The length of the container in base item units.
+    ///
+    /// Note that for strings, this is the length in bytes, not chars.
+    /// See [`byte_len`](`Container::byte_len`) and [`char_len`](`Container::char_len`).
     pub fn len(&self) -> u32 {
         self.array.len()
     }
 
+    /// The length of this string in bytes, as [`len`](`Container::len`).
+    pub fn byte_len(&self) -> u32
+    where
+        Array: TrustedContainer<Item = Character>,
+    {
+        self.len()
+    }
+
+    /// The number of chars in this string.
+    pub fn char_len(&self) -> u32
+    where
+        Array: TrustedContainer<Item = Character>,
+        Array::Slice: AsRef<[u8]>,
+    {
+        let bytes: &[u8] = self.as_slice().as_ref();
+        bytes
+            .iter()
+            .filter(|&&byte| crate::r#impl::is_leading_byte(byte))
+            .count() as u32
+    }
+
     /// Is this container empty?
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -139,6 +234,11 @@ where
         unsafe { perfect::Range::new(0, self.len(), self.id()) }
     }
 
+    /// The full range of the container, if it is not empty.
+    pub fn as_range_nonempty(&self) -> Option<perfect::Range<'id, NonEmpty>> {
+        self.as_range().nonempty()
+    }
+
     /// The start index of the container.
     pub fn start(&self) -> perfect::Index<'id, Unknown> {
         unsafe { perfect::Index::new(0, self.id()) }
@@ -151,13 +251,39 @@ where
 
     /// Take a internally trusted reference to the container.
     pub fn as_ref(&self) -> Container<'id, &'_ Array> {
-        unsafe { mem::transmute(&self.array) }
+        Container {
+            id: self.id,
+            array: &self.array,
+        }
+    }
+
+    /// Tag this container with the marker type `Space`, so that it can only
+    /// be indexed by particles tagged with the same `Space`.
+    pub fn tagged<Space>(&self) -> Tagged<&Self, Space> {
+        Tagged::new(self)
     }
 
     /// Take an internally trusted mutable reference to the container.
-    pub fn as_ref_mut(&mut self) -> Container<'id, &'_ mut Array>
+    pub fn as_ref_mut(&mut self) -> Container<'id, &'_ mut Array> {
+        Container {
+            id: self.id,
+            array: &mut self.array,
+        }
+    }
+
+    /// View this container as its borrowed projection with the same brand,
+    /// e.g. `Container<'id, String>` as `Container<'id, str>`.
+    pub fn project(&self) -> &Container<'id, Array::Target>
+    where
+        Array: Projectable,
     {
-        unsafe { mem::transmute(&mut self.array) }
+        let target: *const Array::Target = self.array.project();
+        // SAFETY: `Container` is `repr(transparent)` over the array (the
+        // brand is zero-sized), so the cast preserves layout; and by the
+        // `Projectable` contract, the projection has the same length and
+        // items as this container, so every particle branded `'id` is
+        // equally valid for it.
+        unsafe { &*(target as *const Container<'id, Array::Target>) }
     }
 
     /// Convert this container into a simple container of the representational
@@ -202,6 +328,45 @@ where
             array: self.array.as_mut(),
         }
     }
+
+    /// The representational units of this container.
+    ///
+    /// The returned slice is [`len`](`Container::len`) units long.
+    pub fn units(&self) -> &[<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]
+    where
+        Array::Slice: AsRef<[<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]>,
+    {
+        self[..].as_ref()
+    }
+
+    /// The representational unit at a raw index, if it is in bounds.
+    pub fn unit_at(
+        &self,
+        ix: u32,
+    ) -> Option<&<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit>
+    where
+        Array::Slice: AsRef<[<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]>,
+    {
+        self.units().get(ix as usize)
+    }
+
+    /// The representational units of this container, mutably.
+    ///
+    /// # Safety
+    ///
+    /// Every particle of this container must remain valid: the units must
+    /// be left forming a valid container with the same item boundaries.
+    /// e.g. for `str`, the bytes must remain valid UTF-8, and every char
+    /// must keep its byte length, such as by only changing ASCII bytes to
+    /// other ASCII bytes.
+    pub unsafe fn units_mut(
+        &mut self,
+    ) -> &mut [<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]
+    where
+        Array: TrustedUnitsMut,
+    {
+        self.array.units_mut()
+    }
 }
 
 /// Upgrading particles
@@ -214,6 +379,15 @@ where
         particle.vet_in_container(self)
     }
 
+    /// Vet a batch of particles for being inbounds and indexable to this
+    /// container, reporting the position of the first that is not.
+    pub fn vet_all<B: BatchVettable<'id>>(
+        &self,
+        particles: B,
+    ) -> Result<B::ContainerVetted, (usize, IndexError)> {
+        particles.vet_all_in_container(self)
+    }
+
     /// Vet an index for being valid, including the one-past-the-end index.
     pub fn vet_or_end(&self, particle: u32) -> Result<perfect::Index<'id, Unknown>, IndexError> {
         Ok(if particle == self.len() {
@@ -222,6 +396,1164 @@ where
             self.vet(particle)?.erased()
         })
     }
+
+    /// Vet an index as by [`vet_or_end`](`Container::vet_or_end`), also
+    /// returning the nearest valid index, which is the vetted index itself
+    /// on success and otherwise the index clamped to the container and
+    /// snapped down to an item boundary.
+    pub fn vet_or_nearest(
+        &self,
+        raw: u32,
+    ) -> (
+        Result<perfect::Index<'id, Unknown>, IndexError>,
+        perfect::Index<'id, Unknown>,
+    ) {
+        if raw >= self.len() {
+            let result = if raw == self.len() {
+                Ok(self.end())
+            } else {
+                Err(IndexError::OutOfBounds)
+            };
+            return (result, self.end());
+        }
+        match unsafe { Array::Item::vet_inbounds(raw, self) } {
+            Some(ix) => (Ok(ix.erased()), ix.erased()),
+            None => {
+                let ix = unsafe { simple::Index::<Unknown>::new(raw, self.id()) };
+                (Err(IndexError::Invalid), self.align_inbounds(ix))
+            }
+        }
+    }
+
+    /// Vet a raw range, expanding it outward to item boundaries.
+    ///
+    /// The start is snapped down and the end snapped up, so the result always
+    /// contains the requested range. Returns `None` if it is out of bounds.
+    pub fn align_range(&self, raw: ops::Range<u32>) -> Option<perfect::Range<'id, Unknown>> {
+        if raw.start > raw.end || raw.end > self.len() {
+            return None;
+        }
+        let start = unsafe { simple::Index::<Unknown>::new(raw.start, self.id()) };
+        let start = self.align_inbounds(start);
+        let end = match self.vet(raw.end) {
+            Ok(end) => end.erased(),
+            Err(_) if raw.end == self.len() => self.end(),
+            Err(_) => {
+                let end = unsafe { simple::Index::<NonEmpty>::new(raw.end, self.id()) };
+                self.after(self.align_inbounds(end))
+            }
+        };
+        Some(unsafe { perfect::Range::new(start.untrusted(), end.untrusted(), self.id()) })
+    }
+
+    /// Vet a range given by its raw start and end offsets.
+    ///
+    /// Returns `Err(Invalid)` if `start` is after `end`.
+    pub fn range_from_raw(
+        &self,
+        start: u32,
+        end: u32,
+    ) -> Result<perfect::Range<'id, Unknown>, IndexError> {
+        if start > end {
+            return Err(IndexError::Invalid);
+        }
+        self.vet(start..end)
+    }
+
+    /// Vet a range encoded by [`perfect::Range::token`].
+    pub fn detoken(&self, token: u64) -> Result<perfect::Range<'id, Unknown>, IndexError> {
+        self.range_from_raw((token >> 32) as u32, token as u32)
+    }
+
+    /// Vet a signed index, where negative indices count back from the end.
+    ///
+    /// Nonnegative indices are vetted as by [`vet`](`Container::vet`).
+    /// A negative index `i` refers to the item containing the unit at
+    /// `len() + i`; e.g. `-1` is the index of the last item.
+    pub fn vet_signed(&self, ix: i64) -> Result<perfect::Index<'id, Unknown>, IndexError> {
+        if ix >= 0 {
+            Ok(self.vet(ix)?.erased())
+        } else {
+            let ix = i64::from(self.len()) + ix;
+            let ix = u32::try_from(ix).map_err(|_| IndexError::OutOfBounds)?;
+            let ix = unsafe { simple::Index::<NonEmpty>::new(ix, self.id()) };
+            Ok(self.align_inbounds(ix).erased())
+        }
+    }
+
+    /// Is the raw byte offset a char boundary of this string (or its length)?
+    ///
+    /// This is a cheaper check than [`vet`](`Container::vet`) when no index is needed.
+    pub fn is_boundary(&self, raw: u32) -> bool
+    where
+        Array: TrustedContainer<Item = Character>,
+        Array::Slice: AsRef<[u8]>,
+    {
+        let bytes: &[u8] = self.as_slice().as_ref();
+        match bytes.get(raw as usize) {
+            Some(&byte) => crate::r#impl::is_leading_byte(byte),
+            None => raw == self.len(),
+        }
+    }
+}
+
+/// Item navigation
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer,
+{
+    /// The index directly after the item at `ix`.
+    /// (This may be the one-past-the-end index.)
+    pub fn after(&self, ix: perfect::Index<'id, NonEmpty>) -> perfect::Index<'id, Unknown> {
+        Array::Item::after(ix, self)
+    }
+
+    /// Align a simple index down to the nearest item boundary.
+    pub(crate) fn align_inbounds<P>(&self, ix: simple::Index<'id, P>) -> perfect::Index<'id, P> {
+        let aligned = if ix.untrusted() < self.len() {
+            (0..=ix.untrusted())
+                .rev()
+                .find(|&i| unsafe { Array::Item::vet_inbounds(i, self) }.is_some())
+                .unwrap_or(0)
+        } else {
+            self.len()
+        };
+        unsafe { perfect::Index::new(aligned, self.id()) }
+    }
+
+    /// The index of the item after the one at `ix`, if there is one.
+    ///
+    /// Unlike [`after`](`Container::after`), this is `None` rather than the
+    /// end index when `ix` is at the last item.
+    pub fn advance<P>(&self, ix: perfect::Index<'id, P>) -> Option<perfect::Index<'id, NonEmpty>> {
+        let ix = self.vet(ix.erased()).ok()?;
+        self.vet(self.after(ix)).ok()
+    }
+
+    /// The index of the item directly before `ix`, if there is one.
+    pub(crate) fn before<P>(
+        &self,
+        ix: perfect::Index<'id, P>,
+    ) -> Option<perfect::Index<'id, NonEmpty>> {
+        Array::Item::before(ix, self)
+    }
+
+    /// The item `n` items after `from`, where `0` is the item at `from`,
+    /// or `None` if that is past the end of the container.
+    pub fn peek<P>(&self, from: perfect::Index<'id, P>, n: u32) -> Option<&Array::Item> {
+        let mut ix = self.vet(from.erased()).ok()?;
+        for _ in 0..n {
+            ix = self.advance(ix)?;
+        }
+        Some(&self[ix])
+    }
+
+    /// The indices of the items directly before and after the one at `ix`,
+    /// if there are any.
+    pub fn neighbors(
+        &self,
+        ix: perfect::Index<'id, NonEmpty>,
+    ) -> (
+        Option<perfect::Index<'id, NonEmpty>>,
+        Option<perfect::Index<'id, NonEmpty>>,
+    ) {
+        (self.before(ix), self.vet(self.after(ix)).ok())
+    }
+}
+
+/// Iteration
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer,
+{
+    /// The indices of every item in the container, in order.
+    pub(crate) fn indices(&self) -> impl Iterator<Item = perfect::Index<'id, NonEmpty>> + '_ {
+        iter::successors(self.vet(self.start()).ok(), move |&ix| self.advance(ix))
+    }
+
+    /// Call `f` with the index of and a reference to every item, in order.
+    ///
+    /// This is a plain loop rather than an iterator adapter chain, which
+    /// can be simpler for the optimizer in hot loops.
+    pub fn for_each_item<F>(&self, mut f: F)
+    where
+        F: FnMut(perfect::Index<'id, NonEmpty>, &Array::Item),
+    {
+        let mut raw = 0;
+        while raw < self.len() {
+            let ix = unsafe { perfect::Index::<NonEmpty>::new(raw, self.id()) };
+            f(ix, &self[ix]);
+            raw = self.after(ix).untrusted();
+        }
+    }
+
+    /// Fold over every item and its index with mutable state, in order,
+    /// stopping early once `f` returns `false`. Returns the final state.
+    pub fn scan_indices<S, F>(&self, init: S, mut f: F) -> S
+    where
+        F: FnMut(&mut S, perfect::Index<'id, NonEmpty>, &Array::Item) -> bool,
+    {
+        let mut state = init;
+        for ix in self.indices() {
+            if !f(&mut state, ix, &self[ix]) {
+                break;
+            }
+        }
+        state
+    }
+
+    /// The indices of the items that satisfy the predicate, in order.
+    pub fn filter_indices<'a, F>(
+        &'a self,
+        mut pred: F,
+    ) -> impl Iterator<Item = perfect::Index<'id, NonEmpty>> + 'a
+    where
+        F: FnMut(&Array::Item) -> bool + 'a,
+    {
+        self.indices().filter(move |&ix| pred(&self[ix]))
+    }
+
+    /// The indices of each pair of adjacent items, `(current, next)`, in order.
+    pub fn pairs(
+        &self,
+    ) -> impl Iterator<Item = (perfect::Index<'id, NonEmpty>, perfect::Index<'id, NonEmpty>)> + '_
+    {
+        let first = self.vet(self.start()).ok();
+        let first = first.and_then(|ix| Some((ix, self.advance(ix)?)));
+        iter::successors(first, move |&(_, next)| Some((next, self.advance(next)?)))
+    }
+
+    /// The maximal runs of adjacent items that compare equal under `eq`, in order.
+    pub fn runs<'a, F>(
+        &'a self,
+        mut eq: F,
+    ) -> impl Iterator<Item = simple::Range<'id, NonEmpty>> + 'a
+    where
+        F: FnMut(&Array::Item, &Array::Item) -> bool + 'a,
+    {
+        let mut next = self.vet(self.start()).ok();
+        iter::from_fn(move || {
+            let start = next?;
+            let mut last = start;
+            next = loop {
+                match self.advance(last) {
+                    Some(ix) if eq(&self[last], &self[ix]) => last = ix,
+                    ix => break ix,
+                }
+            };
+            let end = self.after(last);
+            Some(unsafe { simple::Range::new(start.untrusted(), end.untrusted(), self.id()) })
+        })
+    }
+
+    /// The ranges between items that satisfy `is_sep`, in order, like `str::split`.
+    ///
+    /// Adjacent separators produce empty ranges, as does an empty container.
+    pub fn split_by<'a, F>(
+        &'a self,
+        mut is_sep: F,
+    ) -> impl Iterator<Item = perfect::Range<'id, Unknown>> + 'a
+    where
+        F: FnMut(&Array::Item) -> bool + 'a,
+    {
+        let mut indices = self.indices();
+        let mut start = Some(self.start());
+        iter::from_fn(move || {
+            let field_start = start?.untrusted();
+            let field_end = match indices.by_ref().find(|&ix| is_sep(&self[ix])) {
+                Some(sep) => {
+                    start = Some(self.after(sep));
+                    sep.untrusted()
+                }
+                None => {
+                    start = None;
+                    self.len()
+                }
+            };
+            Some(unsafe { perfect::Range::new(field_start, field_end, self.id()) })
+        })
+    }
+
+    /// Split the container after each item matching `is_end`, keeping the
+    /// matching item at the end of its range, as `str::split_inclusive`.
+    ///
+    /// The last range has no terminator if the container does not end
+    /// with one; there is no trailing empty range.
+    pub fn split_inclusive<'a, F>(
+        &'a self,
+        mut is_end: F,
+    ) -> impl Iterator<Item = perfect::Range<'id, Unknown>> + 'a
+    where
+        F: FnMut(&Array::Item) -> bool + 'a,
+    {
+        let mut indices = self.indices();
+        let mut start = 0;
+        iter::from_fn(move || {
+            if start >= self.len() {
+                return None;
+            }
+            let field_start = start;
+            start = match indices.by_ref().find(|&ix| is_end(&self[ix])) {
+                Some(end) => self.after(end).untrusted(),
+                None => self.len(),
+            };
+            Some(unsafe { perfect::Range::new(field_start, start, self.id()) })
+        })
+    }
+
+    /// The ranges between items that satisfy `is_sep`, in reverse order, like `str::rsplit`.
+    ///
+    /// Adjacent separators produce empty ranges, as does an empty container.
+    pub fn rsplit_by<'a, F>(
+        &'a self,
+        mut is_sep: F,
+    ) -> impl Iterator<Item = perfect::Range<'id, Unknown>> + 'a
+    where
+        F: FnMut(&Array::Item) -> bool + 'a,
+    {
+        let mut end = Some(self.end());
+        iter::from_fn(move || {
+            let field_end = end?;
+            let mut ix = field_end;
+            let field_start = loop {
+                match self.before(ix) {
+                    Some(sep) if is_sep(&self[sep]) => {
+                        end = Some(sep.erased());
+                        break self.after(sep);
+                    }
+                    Some(prev) => ix = prev.erased(),
+                    None => {
+                        end = None;
+                        break self.start();
+                    }
+                }
+            };
+            Some(unsafe {
+                perfect::Range::new(field_start.untrusted(), field_end.untrusted(), self.id())
+            })
+        })
+    }
+
+    /// The ranges of each line of the string, in order, like `str::lines`.
+    ///
+    /// Lines are ended by `\n` or `\r\n`, which are not included in the range.
+    /// A trailing line ending does not produce a final empty line.
+    pub fn lines(&self) -> impl Iterator<Item = perfect::Range<'id, Unknown>> + '_
+    where
+        Array: TrustedContainer<Item = Character>,
+    {
+        let end = self.end();
+        self.split_by(|c| *c == '\n')
+            .filter(move |line| line.start() != end)
+            .map(move |line| {
+                let cr = self
+                    .before(line.end())
+                    .filter(|&last| line.end() != end && line.contains(last) && self[last] == '\r');
+                match cr {
+                    Some(cr) => unsafe {
+                        perfect::Range::new(line.start().untrusted(), cr.untrusted(), self.id())
+                    },
+                    None => line,
+                }
+            })
+    }
+
+    /// A snapshot of every item alongside its raw index, in order.
+    ///
+    /// The indices are unbranded, so snapshots can be compared across scopes.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn indexed_vec(&self) -> Vec<(u32, &Array::Item)> {
+        self.indices()
+            .map(|ix| (ix.untrusted(), &self[ix]))
+            .collect()
+    }
+
+    /// Plan a compaction that keeps only the items that satisfy `keep`.
+    ///
+    /// For each unit of the container, the result holds the raw index that
+    /// unit would have after compaction, or `None` if its item is dropped.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn compaction_map<F>(&self, mut keep: F) -> Vec<Option<u32>>
+    where
+        F: FnMut(&Array::Item) -> bool,
+    {
+        let mut map = Vec::with_capacity(self.len() as usize);
+        let mut next = 0;
+        for ix in self.indices() {
+            let width = self.after(ix).untrusted() - ix.untrusted();
+            if keep(&self[ix]) {
+                map.extend((next..next + width).map(Some));
+                next += width;
+            } else {
+                map.extend((0..width).map(|_| None));
+            }
+        }
+        map
+    }
+
+    /// Apply `f` to every item, in order, collecting the results.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn map_to_vec<U, F>(&self, mut f: F) -> Vec<U>
+    where
+        F: FnMut(&Array::Item) -> U,
+    {
+        self.indices().map(|ix| f(&self[ix])).collect()
+    }
+
+    /// Apply `f` to each sliding window of `N` consecutive items, in order,
+    /// collecting the results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn map_windows<const N: usize, B, F>(&self, mut f: F) -> Vec<B>
+    where
+        F: FnMut(&[&Array::Item; N]) -> B,
+    {
+        assert!(N != 0, "map_windows with zero window size");
+        let items: Vec<&Array::Item> = self.indices().map(|ix| &self[ix]).collect();
+        items
+            .windows(N)
+            .map(|window| {
+                let window = <&[&Array::Item; N]>::try_from(window)
+                    .unwrap_or_else(|_| unsafe { debug_unreachable!() });
+                f(window)
+            })
+            .collect()
+    }
+
+    /// The code points of the string alongside their byte ranges, in order.
+    ///
+    /// Like [`str::char_indices`], but yields trusted ranges.
+    pub fn char_index_ranges(
+        &self,
+    ) -> impl Iterator<Item = (char, perfect::Range<'id, NonEmpty>)> + '_
+    where
+        Array: TrustedContainer<Item = Character>,
+    {
+        self.indices().map(move |ix| {
+            let end = self.after(ix);
+            let range = unsafe { perfect::Range::new(ix.untrusted(), end.untrusted(), self.id()) };
+            (self[ix].as_char(), range)
+        })
+    }
+
+    /// The items at each of the given indices, in the order given.
+    ///
+    /// Indices may repeat; each is yielded as often as it appears.
+    pub fn get_sorted<'a>(
+        &'a self,
+        indices: &'a [perfect::Index<'id, NonEmpty>],
+    ) -> impl Iterator<Item = &'a Array::Item> + Captures<'id> + 'a {
+        indices.iter().map(move |&ix| &self[ix])
+    }
+
+    /// Iterate the items of the container alongside their indices,
+    /// from the last item to the first.
+    pub fn iter_rev(
+        &self,
+    ) -> impl Iterator<Item = (perfect::Index<'id, NonEmpty>, &Array::Item)> + '_
+    where
+        Array::Item: TrustedUnit<Array>,
+    {
+        (0..self.len()).rev().map(move |i| {
+            let ix = unsafe { perfect::Index::new(i, self.id()) };
+            (ix, &self[ix])
+        })
+    }
+
+    /// Mutably iterate the items of the container alongside their indices.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (perfect::Index<'id, NonEmpty>, &mut Array::Item)> + '_
+    where
+        Array: TrustedContainerMut,
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsMut<[Array::Item]>,
+    {
+        let id = self.id();
+        self[..]
+            .as_mut()
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, item)| (unsafe { perfect::Index::new(i as u32, id) }, item))
+    }
+
+    /// Mutably iterate the leading items of the container as disjoint
+    /// arrays of `N` items. Remaining items that do not fill an array are
+    /// not yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn chunks_exact_mut<const N: usize>(
+        &mut self,
+    ) -> impl Iterator<Item = &mut [Array::Item; N]> + '_
+    where
+        Array: TrustedContainerMut,
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsMut<[Array::Item]>,
+    {
+        self[..].as_mut().chunks_exact_mut(N).map(|chunk| {
+            <&mut [Array::Item; N]>::try_from(chunk)
+                .unwrap_or_else(|_| unsafe { debug_unreachable!() })
+        })
+    }
+}
+
+/// Searching
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer,
+{
+    /// Binary search a sorted range of this container with a comparator
+    /// function, as `slice::binary_search_by`.
+    ///
+    /// If a matching item is found, its index is returned in `Ok`. Otherwise,
+    /// the index where a matching item could be inserted while maintaining
+    /// sorted order is returned in `Err`; this may be `range.end()`.
+    pub fn binary_search_in_by<P, D, F>(
+        &self,
+        range: simple::Range<'id, P, D>,
+        mut f: F,
+    ) -> Result<perfect::Index<'id, NonEmpty>, perfect::Index<'id, Unknown>>
+    where
+        Array::Item: TrustedUnit<Array>,
+        F: FnMut(&Array::Item) -> cmp::Ordering,
+    {
+        let mut lo = range.start().untrusted();
+        let mut hi = range.end().untrusted();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let ix = unsafe { perfect::Index::new(mid, self.id()) };
+            match f(&self[ix]) {
+                cmp::Ordering::Less => lo = mid + 1,
+                cmp::Ordering::Greater => hi = mid,
+                cmp::Ordering::Equal => return Ok(ix),
+            }
+        }
+        Err(unsafe { perfect::Index::new(lo, self.id()) })
+    }
+
+    /// Does this container contain an item equal to `item`?
+    pub fn contains_item(&self, item: &Array::Item) -> bool
+    where
+        Array::Item: PartialEq,
+    {
+        self.indices().any(|ix| self[ix] == *item)
+    }
+
+    /// The number of items that satisfy the predicate.
+    pub fn count<F>(&self, mut pred: F) -> u32
+    where
+        F: FnMut(&Array::Item) -> bool,
+    {
+        self.indices().filter(|&ix| pred(&self[ix])).count() as u32
+    }
+
+    /// The first item for which `f` returns `Some`, with its index and the mapped value.
+    pub fn find_map<B, F>(&self, mut f: F) -> Option<(perfect::Index<'id, NonEmpty>, B)>
+    where
+        F: FnMut(&Array::Item) -> Option<B>,
+    {
+        self.indices().find_map(|ix| Some((ix, f(&self[ix])?)))
+    }
+
+    /// The range of the first occurrence of `needle` in this container,
+    /// or `None` if there is none or `needle` is empty.
+    pub fn find_slice(&self, needle: &Array::Slice) -> Option<perfect::Range<'id, NonEmpty>>
+    where
+        Array::Slice: PartialEq + TrustedContainer,
+    {
+        let width = needle.len();
+        if width == 0 {
+            return None;
+        }
+        self.indices()
+            .take_while(|ix| ix.untrusted() <= self.len() - cmp::min(width, self.len()))
+            .find_map(|ix| {
+                let end = self.vet_or_end(ix.untrusted() + width).ok()?;
+                let found =
+                    unsafe { perfect::Range::new(ix.untrusted(), end.untrusted(), self.id()) };
+                if self[found] == *needle {
+                    Some(found)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// The range of the last occurrence of `needle` in this container,
+    /// or `None` if there is none or `needle` is empty.
+    pub fn rfind_slice(&self, needle: &Array::Slice) -> Option<perfect::Range<'id, NonEmpty>>
+    where
+        Array::Slice: PartialEq + TrustedContainer,
+    {
+        let width = needle.len();
+        if width == 0 || width > self.len() {
+            return None;
+        }
+        let mut ix = self.before(self.end());
+        while let Some(start) = ix {
+            if start.untrusted() <= self.len() - width {
+                if let Ok(end) = self.vet_or_end(start.untrusted() + width) {
+                    let found = unsafe {
+                        perfect::Range::new(start.untrusted(), end.untrusted(), self.id())
+                    };
+                    if self[found] == *needle {
+                        return Some(found);
+                    }
+                }
+            }
+            ix = self.before(start);
+        }
+        None
+    }
+
+    /// The range of the first occurrence of `pat` in this string,
+    /// or `None` if there is none or `pat` is empty.
+    ///
+    /// Unlike [`find_slice`](`Container::find_slice`), this uses the
+    /// substring search of `str::find`.
+    pub fn find_str(&self, pat: &str) -> Option<perfect::Range<'id, NonEmpty>>
+    where
+        Array: TrustedContainer<Item = Character>,
+        Array::Slice: AsRef<str>,
+    {
+        if pat.is_empty() {
+            return None;
+        }
+        let start = self.as_slice().as_ref().find(pat)? as u32;
+        let end = start + pat.len() as u32;
+        Some(unsafe { perfect::Range::new(start, end, self.id()) })
+    }
+
+    /// Does this string contain the code point `c`?
+    pub fn contains_char(&self, c: char) -> bool
+    where
+        Array: TrustedContainer<Item = Character>,
+    {
+        self.indices().any(|ix| self[ix] == c)
+    }
+}
+
+/// Splitting
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer,
+{
+    /// The first item of the container and the range of the rest of it,
+    /// or `None` if the container is empty.
+    pub fn split_first(&self) -> Option<(&Array::Item, perfect::Range<'id, Unknown>)> {
+        let first = self.vet(self.start()).ok()?;
+        let after = self.after(first);
+        let rest = unsafe { perfect::Range::new(after.untrusted(), self.len(), self.id()) };
+        Some((&self[first], rest))
+    }
+
+    /// The last item of the container and the range of the rest of it,
+    /// or `None` if the container is empty.
+    pub fn split_last(&self) -> Option<(&Array::Item, perfect::Range<'id, Unknown>)> {
+        let last = self.before(self.end())?;
+        let rest = unsafe { perfect::Range::new(0, last.untrusted(), self.id()) };
+        Some((&self[last], rest))
+    }
+
+    /// The ranges before and after the first item that satisfies `is_sep`,
+    /// like `str::split_once`, or `None` if no item does.
+    pub fn split_once<F>(
+        &self,
+        mut is_sep: F,
+    ) -> Option<(perfect::Range<'id, Unknown>, perfect::Range<'id, Unknown>)>
+    where
+        F: FnMut(&Array::Item) -> bool,
+    {
+        let sep = self.indices().find(|&ix| is_sep(&self[ix]))?;
+        let after = self.after(sep);
+        unsafe {
+            Some((
+                perfect::Range::new(0, sep.untrusted(), self.id()),
+                perfect::Range::new(after.untrusted(), self.len(), self.id()),
+            ))
+        }
+    }
+
+    /// The leading items of this container as arrays of `N` items,
+    /// and the range of the remaining items that do not fill an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn as_chunks<const N: usize>(
+        &self,
+    ) -> (
+        impl Iterator<Item = &[Array::Item; N]> + '_,
+        simple::Range<'id, Unknown>,
+    )
+    where
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsRef<[Array::Item]>,
+    {
+        let chunks = self.as_slice().as_ref().chunks_exact(N);
+        let rest = chunks.remainder().len() as u32;
+        let rest = unsafe { simple::Range::new(self.len() - rest, self.len(), self.id()) };
+        let chunks = chunks.map(|chunk| {
+            <&[Array::Item; N]>::try_from(chunk).unwrap_or_else(|_| unsafe { debug_unreachable!() })
+        });
+        (chunks, rest)
+    }
+
+    /// The container as an array of exactly `N` items,
+    /// or `None` if its length is not `N`.
+    pub fn as_array<const N: usize>(&self) -> Option<&[Array::Item; N]>
+    where
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsRef<[Array::Item]>,
+    {
+        <&[Array::Item; N]>::try_from(self.as_slice().as_ref()).ok()
+    }
+
+    /// Create a fresh indexing scope for the slice of this container
+    /// covered by `range`.
+    ///
+    /// The slice is branded with its own `'id`, distinct from this container,
+    /// so particles cannot be mixed up between the two index spaces.
+    pub fn subscope<P, F, Out>(&self, range: perfect::Range<'id, P>, f: F) -> Out
+    where
+        Array::Slice: TrustedContainer,
+        F: for<'b> FnOnce(&'b Container<'b, Array::Slice>) -> Out,
+    {
+        crate::scope(&self[range], f)
+    }
+}
+
+/// Comparison
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer,
+{
+    /// Are the contents of this container equal to those of `other`?
+    ///
+    /// The containers may be from different scopes; only the data is compared.
+    pub fn content_eq<'b, B: ?Sized>(&self, other: &Container<'b, B>) -> bool
+    where
+        B: TrustedContainer,
+        Array::Slice: PartialEq<B::Slice>,
+    {
+        self[..] == other[..]
+    }
+
+    /// The length in units of the longest common prefix of items
+    /// of this container and `other`.
+    ///
+    /// The containers may be from different scopes; only the data is compared.
+    pub fn common_prefix_len<'b, B: ?Sized>(&self, other: &Container<'b, B>) -> u32
+    where
+        B: TrustedContainer,
+        Array::Item: PartialEq<B::Item>,
+    {
+        let mut len = 0;
+        let mut a = self.vet(self.start()).ok();
+        let mut b = other.vet(other.start()).ok();
+        while let (Some(ix), Some(jx)) = (a, b) {
+            if self[ix] != other[jx] {
+                break;
+            }
+            len = self.after(ix).untrusted();
+            a = self.advance(ix);
+            b = other.advance(jx);
+        }
+        len
+    }
+
+    /// The length in units of the longest common suffix of items
+    /// of this container and `other`.
+    ///
+    /// The containers may be from different scopes; only the data is compared.
+    pub fn common_suffix_len<'b, B: ?Sized>(&self, other: &Container<'b, B>) -> u32
+    where
+        B: TrustedContainer,
+        Array::Item: PartialEq<B::Item>,
+    {
+        let mut start = self.len();
+        let mut a = self.before(self.end());
+        let mut b = other.before(other.end());
+        while let (Some(ix), Some(jx)) = (a, b) {
+            if self[ix] != other[jx] {
+                break;
+            }
+            start = ix.untrusted();
+            a = self.before(ix);
+            b = other.before(jx);
+        }
+        self.len() - start
+    }
+
+    /// Compare the contents of this container to those of `other`.
+    ///
+    /// The containers may be from different scopes; only the data is compared.
+    pub fn content_cmp<'b, B: ?Sized>(&self, other: &Container<'b, B>) -> Option<cmp::Ordering>
+    where
+        B: TrustedContainer,
+        Array::Slice: PartialOrd<B::Slice>,
+    {
+        self[..].partial_cmp(&other[..])
+    }
+
+    /// Are the items of this container sorted according to `le`?
+    ///
+    /// `le(a, b)` should return whether `a` may come before `b`.
+    pub fn is_sorted_by<F>(&self, mut le: F) -> bool
+    where
+        F: FnMut(&Array::Item, &Array::Item) -> bool,
+    {
+        self.pairs().all(|(a, b)| le(&self[a], &self[b]))
+    }
+
+    /// A witness that the items of this container are sorted,
+    /// or `None` if they are not.
+    pub fn check_sorted(&self) -> Option<Sorted<'_, 'id>>
+    where
+        Array::Item: PartialOrd,
+    {
+        if self.is_sorted_by(|a, b| a <= b) {
+            Some(unsafe { Sorted::new(self.id()) })
+        } else {
+            None
+        }
+    }
+
+    /// Are the contents of the ranges `a` and `b` of this container equal?
+    pub fn range_eq<P, Q, D, E>(
+        &self,
+        a: simple::Range<'id, P, D>,
+        b: simple::Range<'id, Q, E>,
+    ) -> bool
+    where
+        Array::Slice: AsRef<[<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]>,
+        <<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit: PartialEq,
+    {
+        let units = self.units();
+        let a = a.start().untrusted() as usize..a.end().untrusted() as usize;
+        let b = b.start().untrusted() as usize..b.end().untrusted() as usize;
+        // SAFETY: both ranges are branded, so they lie within the container,
+        // and `units` is exactly `len` units long.
+        a.len() == b.len() && unsafe { units.get_unchecked(a) == units.get_unchecked(b) }
+    }
+
+    /// Feed the units of `range` of this container into `state`.
+    pub fn hash_range<P, D, H>(&self, range: simple::Range<'id, P, D>, state: &mut H)
+    where
+        Array::Slice: AsRef<[<<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit]>,
+        <<Array as TrustedContainer>::Item as TrustedItem<Array>>::Unit: Hash,
+        H: Hasher,
+    {
+        let r = range.start().untrusted() as usize..range.end().untrusted() as usize;
+        // SAFETY: `range` is branded, so it lies within the container, and
+        // `units` is exactly `len` units long.
+        unsafe { self.units().get_unchecked(r) }.hash(state)
+    }
+
+    /// Does this container start with `prefix`?
+    pub fn starts_with(&self, prefix: &Array::Slice) -> bool
+    where
+        Array::Slice: PartialEq + TrustedContainer,
+    {
+        match self.vet_or_end(prefix.len()) {
+            Ok(end) => self[..end] == *prefix,
+            Err(_) => false,
+        }
+    }
+
+    /// Does this container end with `suffix`?
+    pub fn ends_with(&self, suffix: &Array::Slice) -> bool
+    where
+        Array::Slice: PartialEq + TrustedContainer,
+    {
+        let start = match self.len().checked_sub(suffix.len()) {
+            Some(start) => start,
+            None => return false,
+        };
+        match self.vet_or_end(start) {
+            Ok(start) => self[start..] == *suffix,
+            Err(_) => false,
+        }
+    }
+}
+
+/// Editing
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainer<Item = Character>,
+{
+    /// Plan the replacement of `range` with `with`, to be applied to the
+    /// owned string once this scope has ended.
+    pub fn plan_replace<'a, P>(
+        &self,
+        range: perfect::Range<'id, P>,
+        with: &'a str,
+    ) -> EditPlan<'a> {
+        EditPlan {
+            range: range.untrusted(),
+            with,
+        }
+    }
+}
+
+/// Mutation
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: TrustedContainerMut,
+{
+    /// Split the container at `at` into two disjoint mutable containers,
+    /// each with its own fresh brand, and run `f` on them.
+    pub fn split_at_mut<P, F, R>(&mut self, at: perfect::Index<'id, P>, f: F) -> R
+    where
+        Array: TrustedSplitMut,
+        Array::Slice: TrustedContainerMut,
+        F: for<'a, 'b> FnOnce(
+            &mut Container<'a, Array::Slice>,
+            &mut Container<'b, Array::Slice>,
+        ) -> R,
+    {
+        // SAFETY: `at` is a branded perfect index, so it is in bounds (or the
+        // end index) and on an item boundary.
+        let (left, right) = unsafe { self.array.split_unchecked_mut(at.untrusted()) };
+        generativity::make_guard!(left_guard);
+        generativity::make_guard!(right_guard);
+        f(
+            Container::new_ref_mut(left, left_guard),
+            Container::new_ref_mut(right, right_guard),
+        )
+    }
+
+    /// Vet a raw index and mutably access the item there in one call.
+    pub fn get_vetted_mut(&mut self, raw: u32) -> Result<&mut Array::Item, IndexError> {
+        let ix = self.vet(raw)?;
+        Ok(&mut self[ix])
+    }
+
+    /// Replace the item at `ix` with `value`, returning the old item.
+    pub fn replace(&mut self, ix: perfect::Index<'id, NonEmpty>, value: Array::Item) -> Array::Item
+    where
+        Array::Item: Sized,
+    {
+        mem::replace(&mut self[ix], value)
+    }
+
+    /// Move the first of each run of consecutive items for which `same`
+    /// returns true to the front, like `Vec::dedup_by`, returning the end
+    /// of the deduplicated prefix.
+    ///
+    /// The container's length is unchanged; the removed duplicates are left
+    /// after the returned index in unspecified order.
+    pub fn dedup_by<F>(&mut self, mut same: F) -> perfect::Index<'id, Unknown>
+    where
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsMut<[Array::Item]>,
+        F: FnMut(&mut Array::Item, &mut Array::Item) -> bool,
+    {
+        let slice: &mut [Array::Item] = self.as_slice_mut().as_mut();
+        let len = <[Array::Item]>::len(slice);
+        let mut write = cmp::min(len, 1);
+        for read in 1..len {
+            let (kept, rest) = slice.split_at_mut(read);
+            if !same(&mut rest[0], &mut kept[write - 1]) {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+        unsafe { perfect::Index::new(write as u32, self.id()) }
+    }
+
+    /// Convert the ASCII bytes in `range` to uppercase in place,
+    /// for byte and string containers.
+    ///
+    /// This never changes byte lengths, so all particles remain valid.
+    pub fn make_ascii_uppercase_in<P, D>(&mut self, range: simple::Range<'id, P, D>)
+    where
+        Array: TrustedUnitsMut,
+        Array::Item: TrustedAsciiCase<Array>,
+    {
+        let r = range.start().untrusted() as usize..range.end().untrusted() as usize;
+        // SAFETY: the items are `TrustedAsciiCase`, so changing the case of
+        // ASCII bytes leaves every item boundary intact; the branded range
+        // is in bounds.
+        unsafe { self.units_mut().get_unchecked_mut(r) }.make_ascii_uppercase()
+    }
+
+    /// Convert the ASCII bytes in `range` to lowercase in place,
+    /// for byte and string containers.
+    ///
+    /// This never changes byte lengths, so all particles remain valid.
+    pub fn make_ascii_lowercase_in<P, D>(&mut self, range: simple::Range<'id, P, D>)
+    where
+        Array: TrustedUnitsMut,
+        Array::Item: TrustedAsciiCase<Array>,
+    {
+        let r = range.start().untrusted() as usize..range.end().untrusted() as usize;
+        // SAFETY: the items are `TrustedAsciiCase`, so changing the case of
+        // ASCII bytes leaves every item boundary intact; the branded range
+        // is in bounds.
+        unsafe { self.units_mut().get_unchecked_mut(r) }.make_ascii_lowercase()
+    }
+
+    /// Swap the items in `range` with those of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` and `other` have different lengths.
+    pub fn swap_with_slice<P, D>(
+        &mut self,
+        range: simple::Range<'id, P, D>,
+        other: &mut [Array::Item],
+    ) where
+        Array::Item: TrustedUnit<Array>,
+        Array::Slice: AsMut<[Array::Item]>,
+    {
+        self[range].as_mut().swap_with_slice(other)
+    }
+
+    /// Copy the items of `src` into `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` and `src` have different lengths.
+    pub fn copy_from_slice<P, D>(&mut self, range: simple::Range<'id, P, D>, src: &[Array::Item])
+    where
+        Array::Item: TrustedUnit<Array> + Copy,
+        Array::Slice: AsMut<[Array::Item]>,
+    {
+        self[range].as_mut().copy_from_slice(src)
+    }
+}
+
+/// Growing
+impl<'id, Array: ?Sized> Container<'id, Array>
+where
+    Array: GrowableContainer,
+{
+    /// Append an item to the end of the container, returning its index.
+    ///
+    /// Existing indices remain valid, as no items are moved or removed.
+    /// A growable container cannot be cloned, so no other container with
+    /// this brand can be left shorter than the returned index:
+    ///
+    /// ```rust,compile_fail
+    /// # use windex::scope_val;
+    /// scope_val(vec![0], |v| {
+    ///     let mut w = v.clone();
+    ///     let ix = w.push(1);
+    ///     let _ = v[ix]; // would be out of bounds
+    /// })
+    /// ```
+    pub fn push(&mut self, item: Array::OwnedItem) -> perfect::Index<'id, NonEmpty> {
+        let ix = self.len();
+        self.array.push(item);
+        assert!(self.len() > ix, "container length overflowed u32");
+        unsafe { perfect::Index::new(ix, self.id()) }
+    }
+
+    /// The index `ix`, first growing the container with items from `make`
+    /// until it is in bounds, if necessary.
+    ///
+    /// Every slot between the old end and `ix` is filled with a fresh item
+    /// from `make`, in order, so `make` is called `ix + 1 - len()` times.
+    /// As with [`push`](`Container::push`), the returned index cannot be out
+    /// of bounds of another container with this brand.
+    pub fn get_or_push<F>(&mut self, ix: u32, mut make: F) -> perfect::Index<'id, NonEmpty>
+    where
+        Array: GrowableContainer<OwnedItem = <Array as TrustedContainer>::Item>,
+        Array::Item: TrustedUnit<Array>,
+        F: FnMut() -> Array::Item,
+    {
+        while self.len() <= ix {
+            self.push(make());
+        }
+        unsafe { perfect::Index::new(ix, self.id()) }
+    }
+
+    /// Append clones of `items` to the end of the container,
+    /// returning the range they now occupy, or `None` if `items` is empty.
+    ///
+    /// As with [`push`](`Container::push`), the returned range cannot be out
+    /// of bounds of another container with this brand.
+    pub fn append_slice(&mut self, items: &[Array::Item]) -> Option<simple::Range<'id, NonEmpty>>
+    where
+        Array: GrowableContainer<OwnedItem = <Array as TrustedContainer>::Item>,
+        Array::Item: TrustedUnit<Array> + Clone,
+    {
+        let start = self.len();
+        items.iter().cloned().for_each(|item| self.array.push(item));
+        assert!(self.len() >= start, "container length overflowed u32");
+        unsafe { simple::Range::<Unknown>::new(start, self.len(), self.id()) }.nonempty()
+    }
+
+    /// Append a string to the end of the container,
+    /// returning the range it now occupies, or `None` if `s` is empty.
+    ///
+    /// As with [`push`](`Container::push`), the returned range cannot be out
+    /// of bounds of another container with this brand.
+    pub fn append_str(&mut self, s: &str) -> Option<perfect::Range<'id, NonEmpty>>
+    where
+        Array: GrowableContainer<OwnedItem = char>,
+    {
+        let start = self.len();
+        s.chars().for_each(|c| self.array.push(c));
+        assert!(self.len() >= start, "container length overflowed u32");
+        unsafe { perfect::Range::<Unknown>::new(start, self.len(), self.id()) }.nonempty()
+    }
+
+    /// Truncate the container to `new_len` units, then create a fresh
+    /// indexing scope for the shortened container.
+    ///
+    /// Truncation invalidates indices, so this consumes the container and
+    /// with it the old brand; no stale particles can be used afterwards.
+    /// As with [`into_untrusted`](`Container::into_untrusted`), this is only
+    /// available for the container given to you by your scope.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on an item boundary.
+    pub fn truncate_and_rescope<F, Out>(self, new_len: u32, f: F) -> Out
+    where
+        Array: Sized + 'id,
+        F: for<'b> FnOnce(Container<'b, Array>) -> Out,
+    {
+        let mut array = self.into_untrusted();
+        array.truncate(new_len);
+        crate::scope_val(array, f)
+    }
+}
+
+/// Strings
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+impl<'id> Container<'id, String> {
+    /// Remove every char for which `keep` returns `false`, compacting the
+    /// kept chars toward the front, then create a fresh indexing scope for
+    /// the filtered string.
+    ///
+    /// Compaction moves chars to different byte offsets, so an index from
+    /// before could land in the middle of a char afterwards; and the string
+    /// shrinks, so it could be out of bounds. Thus, as with
+    /// [`truncate_and_rescope`](`Container::truncate_and_rescope`), this
+    /// consumes the container and with it the old brand, and the new end
+    /// is the end of the new scope's container.
+    pub fn retain_chars_and_rescope<K, F, Out>(self, keep: K, f: F) -> Out
+    where
+        K: FnMut(char) -> bool,
+        F: for<'b> FnOnce(Container<'b, String>) -> Out,
+    {
+        let mut string = self.into_untrusted();
+        string.retain(keep);
+        crate::scope_val(string, f)
+    }
 }
 
 // ~~~ Accessors ~~~ //
@@ -346,14 +1678,14 @@ where
 
 // ~ ref ~ //
 
-impl<'id, Array: ?Sized, P> ops::Index<simple::Range<'id, P>> for Container<'id, Array>
+impl<'id, Array: ?Sized, P, D> ops::Index<simple::Range<'id, P, D>> for Container<'id, Array>
 where
     Array: TrustedContainer,
     Array::Item: TrustedUnit<Array>,
 {
     type Output = Array::Slice;
 
-    fn index(&self, index: simple::Range<'id, P>) -> &Self::Output {
+    fn index(&self, index: simple::Range<'id, P, D>) -> &Self::Output {
         unsafe { self.array.slice_unchecked(index.untrusted()) }
     }
 }
@@ -401,12 +1733,12 @@ where
 
 // ~ mut ~ //
 
-impl<'id, Array: ?Sized, P> ops::IndexMut<simple::Range<'id, P>> for Container<'id, Array>
+impl<'id, Array: ?Sized, P, D> ops::IndexMut<simple::Range<'id, P, D>> for Container<'id, Array>
 where
     Array: TrustedContainerMut,
     Array::Item: TrustedUnit<Array>,
 {
-    fn index_mut(&mut self, index: simple::Range<'id, P>) -> &mut Self::Output {
+    fn index_mut(&mut self, index: simple::Range<'id, P, D>) -> &mut Self::Output {
         unsafe { self.array.slice_unchecked_mut(index.untrusted()) }
     }
 }
@@ -446,6 +1778,45 @@ where
     }
 }
 
+// ~~ Tagged ~~ //
+
+impl<'id, Array: ?Sized, Space> ops::Index<Tagged<perfect::Index<'id, NonEmpty>, Space>>
+    for Tagged<&Container<'id, Array>, Space>
+where
+    Array: TrustedContainer,
+{
+    type Output = Array::Item;
+
+    fn index(&self, index: Tagged<perfect::Index<'id, NonEmpty>, Space>) -> &Self::Output {
+        &self.untag()[index.untag()]
+    }
+}
+
+impl<'id, Array: ?Sized, P, Space> ops::Index<Tagged<perfect::Range<'id, P>, Space>>
+    for Tagged<&Container<'id, Array>, Space>
+where
+    Array: TrustedContainer,
+{
+    type Output = Array::Slice;
+
+    fn index(&self, index: Tagged<perfect::Range<'id, P>, Space>) -> &Self::Output {
+        &self.untag()[index.untag()]
+    }
+}
+
+impl<'id, Array: ?Sized, Space> ops::Index<Tagged<simple::Index<'id, NonEmpty>, Space>>
+    for Tagged<&Container<'id, Array>, Space>
+where
+    Array: TrustedContainer,
+    Array::Item: TrustedUnit<Array>,
+{
+    type Output = Array::Item;
+
+    fn index(&self, index: Tagged<simple::Index<'id, NonEmpty>, Space>) -> &Self::Output {
+        &self.untag()[index.untag()]
+    }
+}
+
 // ~~~ Deref ~~~ //
 
 impl<'id, Array: ?Sized, D> ops::Deref for Container<'id, D>
@@ -483,14 +1854,19 @@ where
 
 impl<'id, Array: Copy> Copy for Container<'id, Array> where Array: TrustedContainer {}
 
-impl<'id, Array: Clone> Clone for Container<'id, Array>
+/// Only containers of `Copy` arrays, such as shared references, are `Clone`.
+///
+/// Containers of owned arrays like `Vec<T>` or `String` used to be `Clone`
+/// as well. However, a clone shares the brand, so pushing to one of the two
+/// would create indices that are out of bounds for the other (and the safety
+/// contract of [`GrowableContainer`] excludes `Copy` types for the same
+/// reason). To duplicate an owned container, clone its
+/// [`untrusted`](`Container::untrusted`) array and create a new scope.
+impl<'id, Array: Copy> Clone for Container<'id, Array>
 where
     Array: TrustedContainer,
 {
     fn clone(&self) -> Self {
-        Container {
-            array: self.untrusted().clone(),
-            id: self.id(),
-        }
+        *self
     }
 }
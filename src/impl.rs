@@ -9,6 +9,9 @@ use {
     debug_unreachable::debug_unreachable,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
 /// IMPORTANT safety note: `ix < self.len() as u32` is enough both when
 /// `usize <= u32` and `usize > u32`. If `usize <= u32`, this is lossless.
 /// If `usize > u32`, the worst that will happen is that the length checked
@@ -61,6 +64,28 @@ where
     }
 }
 
+// cannot name D::Target [rust-lang/rust#60871]
+unsafe impl<D> TrustedUnitsMut for D
+where
+    D::Target: TrustedUnitsMut,
+    D: ops::DerefMut + ops::Deref,
+{
+    unsafe fn units_mut(&mut self) -> &mut [<Self::Item as TrustedItem<Self>>::Unit] {
+        <D::Target>::units_mut(self)
+    }
+}
+
+// cannot name D::Target [rust-lang/rust#60871]
+unsafe impl<D> TrustedSplitMut for D
+where
+    D::Target: TrustedSplitMut,
+    D: ops::DerefMut + ops::Deref,
+{
+    unsafe fn split_unchecked_mut(&mut self, at: u32) -> (&mut Self::Slice, &mut Self::Slice) {
+        <D::Target>::split_unchecked_mut(self, at)
+    }
+}
+
 unsafe impl<T: ?Sized, Array: ?Sized, D> TrustedItem<D> for T
 where
     T: TrustedItem<Array>,
@@ -82,9 +107,20 @@ where
     ) -> Option<Index<'id, NonEmpty>> {
         T::vet_inbounds(ix, container)
     }
+
+    fn after<'id>(ix: Index<'id, NonEmpty>, container: &Container<'id, D>) -> Index<'id, Unknown> {
+        T::after(ix, container)
+    }
+
+    fn before<'id, P>(
+        ix: Index<'id, P>,
+        container: &Container<'id, D>,
+    ) -> Option<Index<'id, NonEmpty>> {
+        T::before(ix, container)
+    }
 }
 
-unsafe impl<T, Array, D> TrustedUnit<D> for T
+unsafe impl<T, Array: ?Sized, D> TrustedUnit<D> for T
 where
     T: TrustedUnit<Array>,
     Array: TrustedContainer<Item = T>,
@@ -92,6 +128,14 @@ where
 {
 }
 
+unsafe impl<T: ?Sized, Array: ?Sized, D> TrustedAsciiCase<D> for T
+where
+    T: TrustedAsciiCase<Array>,
+    Array: TrustedContainer<Item = T>,
+    D: ops::Deref<Target = Array>,
+{
+}
+
 // ~~~ Slices ~~~ //
 
 unsafe impl<T> TrustedContainer for [T] {
@@ -129,6 +173,19 @@ unsafe impl<T> TrustedContainerMut for [T] {
     }
 }
 
+unsafe impl<T> TrustedUnitsMut for [T] {
+    unsafe fn units_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+unsafe impl<T> TrustedSplitMut for [T] {
+    unsafe fn split_unchecked_mut(&mut self, at: u32) -> (&mut [T], &mut [T]) {
+        let at = to_usize(at, self);
+        self.split_at_mut(at)
+    }
+}
+
 unsafe impl<T> TrustedUnit<[T]> for T {}
 unsafe impl<T> TrustedItem<[T]> for T {
     type Unit = T;
@@ -151,12 +208,31 @@ unsafe impl<T> TrustedItem<[T]> for T {
         debug_assert!(ix < container.len());
         Some(Index::new(ix, container.id()))
     }
+
+    fn after<'id>(
+        ix: Index<'id, NonEmpty>,
+        container: &Container<'id, [T]>,
+    ) -> Index<'id, Unknown> {
+        unsafe { Index::new(ix.untrusted() + 1, container.id()) }
+    }
+
+    fn before<'id, P>(
+        ix: Index<'id, P>,
+        container: &Container<'id, [T]>,
+    ) -> Option<Index<'id, NonEmpty>> {
+        match ix.untrusted() {
+            0 => None,
+            i => Some(unsafe { Index::new(i - 1, container.id()) }),
+        }
+    }
 }
 
+unsafe impl TrustedAsciiCase<[u8]> for u8 {}
+
 // ~~~ Strings ~~~ //
 
 #[inline]
-fn is_leading_byte(byte: u8) -> bool {
+pub(crate) fn is_leading_byte(byte: u8) -> bool {
     // We want to accept 0b0xxx_xxxx or 0b11xx_xxxx
     // Copied from str::is_char_boundary
     // This is bit magic equivalent to: b < 128 || b >= 192
@@ -189,7 +265,7 @@ unsafe impl TrustedContainer for str {
         let r = to_usize(r.start, self)..to_usize(r.end, self);
         debug_assert!(self.is_char_boundary(r.start));
         debug_assert!(self.is_char_boundary(r.end));
-        debug_assert!(r.start < r.end);
+        debug_assert!(r.start <= r.end);
         self.get_unchecked(r)
     }
 }
@@ -212,11 +288,25 @@ unsafe impl TrustedContainerMut for str {
         let r = to_usize(r.start, self)..to_usize(r.end, self);
         debug_assert!(self.is_char_boundary(r.start));
         debug_assert!(self.is_char_boundary(r.end));
-        debug_assert!(r.start < r.end);
+        debug_assert!(r.start <= r.end);
         self.get_unchecked_mut(r)
     }
 }
 
+unsafe impl TrustedUnitsMut for str {
+    unsafe fn units_mut(&mut self) -> &mut [u8] {
+        self.as_bytes_mut()
+    }
+}
+
+unsafe impl TrustedSplitMut for str {
+    unsafe fn split_unchecked_mut(&mut self, at: u32) -> (&mut str, &mut str) {
+        let at = to_usize(at, self);
+        debug_assert!(self.is_char_boundary(at));
+        self.split_at_mut(at)
+    }
+}
+
 unsafe impl TrustedItem<str> for Character {
     type Unit = u8;
 
@@ -234,4 +324,127 @@ unsafe impl TrustedItem<str> for Character {
             None
         }
     }
+
+    fn after<'id>(
+        ix: Index<'id, NonEmpty>,
+        container: &Container<'id, str>,
+    ) -> Index<'id, Unknown> {
+        let width = container[ix].len() as u32;
+        unsafe { Index::new(ix.untrusted() + width, container.id()) }
+    }
+}
+
+unsafe impl TrustedAsciiCase<str> for Character {}
+
+// ~~~ Growable ~~~ //
+
+unsafe impl<Array: ?Sized> GrowableContainer for &mut Array
+where
+    Array: GrowableContainer,
+{
+    type OwnedItem = Array::OwnedItem;
+
+    fn push(&mut self, item: Array::OwnedItem) {
+        Array::push(self, item)
+    }
+
+    fn truncate(&mut self, len: u32) {
+        Array::truncate(self, len)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl<T> GrowableContainer for Vec<T> {
+    type OwnedItem = T;
+
+    fn push(&mut self, item: T) {
+        Vec::push(self, item)
+    }
+
+    fn truncate(&mut self, len: u32) {
+        Vec::truncate(self, len as usize)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl GrowableContainer for String {
+    type OwnedItem = char;
+
+    fn push(&mut self, item: char) {
+        String::push(self, item)
+    }
+
+    fn truncate(&mut self, len: u32) {
+        String::truncate(self, len as usize)
+    }
+}
+
+// ~~~ Projections ~~~ //
+
+unsafe impl<Array: ?Sized> Projectable for &Array
+where
+    Array: TrustedContainer,
+{
+    type Target = Array;
+
+    fn project(&self) -> &Array {
+        self
+    }
+}
+
+unsafe impl<Array: ?Sized> Projectable for &mut Array
+where
+    Array: TrustedContainer,
+{
+    type Target = Array;
+
+    fn project(&self) -> &Array {
+        self
+    }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl<T> Projectable for Vec<T> {
+    type Target = [T];
+
+    fn project(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl<T> Projectable for Box<[T]> {
+    type Target = [T];
+
+    fn project(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl Projectable for String {
+    type Target = str;
+
+    fn project(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+unsafe impl Projectable for Box<str> {
+    type Target = str;
+
+    fn project(&self) -> &str {
+        self
+    }
+}
+
+// ~~~ Captures ~~~ //
+
+impl<'a, T: ?Sized> Captures<'a> for T {}
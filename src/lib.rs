@@ -28,6 +28,9 @@
 #![deny(rust_2018_idioms, unconditional_recursion)]
 #![cfg_attr(feature = "doc", feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod container;
 mod r#impl;
 
@@ -35,9 +38,19 @@ pub mod particle;
 pub mod proof;
 pub mod traits;
 
-use {crate::traits::TrustedContainer, core::ops, debug_unreachable::debug_unreachable};
+use {
+    crate::{particle::perfect, traits::TrustedContainer},
+    core::{cmp, convert::TryFrom, ops, str},
+    debug_unreachable::debug_unreachable,
+};
+
+pub use crate::{
+    container::Container,
+    proof::{NonEmpty, Unknown},
+};
 
-pub use crate::container::Container;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
 /// Create an indexing scope for a borrowed container.
 ///
@@ -93,6 +106,109 @@ where
     f(Container::new(array, guard))
 }
 
+/// Create an indexing scope for a borrowed container that is not empty.
+///
+/// This is [`scope`] where the closure is also given the full range of the
+/// container as a nonempty range. If the container is empty, the closure
+/// is not called and `None` is returned.
+pub fn scope_nonempty<Array: ?Sized, F, Out>(array: &Array, f: F) -> Option<Out>
+where
+    Array: TrustedContainer,
+    F: for<'id> FnOnce(&'id Container<'id, Array>, perfect::Range<'id, NonEmpty>) -> Out,
+{
+    scope(array, |container| {
+        let range = container.as_range_nonempty()?;
+        Some(f(container, range))
+    })
+}
+
+/// Create a fallible indexing scope for a borrowed container.
+///
+/// This is [`scope`] for closures that return a `Result`, so that `?` can be
+/// used to propagate errors out of the scope. As with the output of `scope`,
+/// neither the success nor the error type can contain particles branded with
+/// `'id`.
+pub fn try_scope<Array: ?Sized, F, T, E>(array: &Array, f: F) -> Result<T, E>
+where
+    Array: TrustedContainer,
+    F: for<'id> FnOnce(&'id Container<'id, Array>) -> Result<T, E>,
+{
+    scope(array, f)
+}
+
+/// Create a fallible indexing scope for a mutably borrowed container.
+///
+/// This is [`scope_mut`] for closures that return a `Result`, so that `?` can
+/// be used to propagate errors out of the scope. As with the output of
+/// `scope_mut`, neither the success nor the error type can contain particles
+/// branded with `'id`.
+pub fn try_scope_mut<Array: ?Sized, F, T, E>(array: &mut Array, f: F) -> Result<T, E>
+where
+    Array: TrustedContainer,
+    F: for<'id> FnOnce(&'id Container<'id, Array>) -> Result<T, E>,
+{
+    scope_mut(array, f)
+}
+
+/// Create a fallible indexing scope for an owned container.
+///
+/// This is [`scope_val`] for closures that return a `Result`, so that `?` can
+/// be used to propagate errors out of the scope. As with the output of
+/// `scope_val`, neither the success nor the error type can contain particles
+/// branded with `'id`.
+pub fn try_scope_val<Array, F, T, E>(array: Array, f: F) -> Result<T, E>
+where
+    Array: TrustedContainer,
+    F: for<'id> FnOnce(Container<'id, Array>) -> Result<T, E>,
+{
+    scope_val(array, f)
+}
+
+/// Create an indexing scope for a byte buffer validated as UTF-8.
+///
+/// The bytes are validated once up front; if they are not UTF-8, the
+/// scope is not run and the validation error is returned instead.
+pub fn scope_str_from_utf8<F, Out>(bytes: &[u8], f: F) -> Result<Out, str::Utf8Error>
+where
+    F: for<'id> FnOnce(&'id Container<'id, str>) -> Out,
+{
+    Ok(scope(str::from_utf8(bytes)?, f))
+}
+
+/// Create an indexing scope for an owned concatenation of two containers.
+///
+/// The containers may be from different scopes. Their contents are cloned
+/// into a new `Vec`, which is branded afresh for the new scope; particles
+/// of either input container cannot be used with it.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+pub fn concat_scope<'a, 'b, T, F, Out>(a: &Container<'a, [T]>, b: &Container<'b, [T]>, f: F) -> Out
+where
+    T: Clone,
+    F: for<'id> FnOnce(Container<'id, Vec<T>>) -> Out,
+{
+    let mut array = Vec::with_capacity(a.untrusted().len() + b.untrusted().len());
+    array.extend_from_slice(a.untrusted());
+    array.extend_from_slice(b.untrusted());
+    scope_val(array, f)
+}
+
+/// Create an indexing scope for a `Vec` filled by `build`.
+///
+/// The `Vec` is allocated with capacity `cap` and passed to `build` to be
+/// filled, then branded for the indexing scope `f`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+pub fn build_scope<T, B, F, Out>(cap: usize, build: B, f: F) -> Out
+where
+    B: FnOnce(&mut Vec<T>),
+    F: for<'id> FnOnce(Container<'id, Vec<T>>) -> Out,
+{
+    let mut array = Vec::with_capacity(cap);
+    build(&mut array);
+    scope_val(array, f)
+}
+
 /// A utf8 string slice of exactly one codepoint.
 ///
 /// This type is two `usize` large, so you'll probably want to read the
@@ -127,3 +243,105 @@ impl Character {
             .unwrap_or_else(|| unsafe { debug_unreachable!() })
     }
 }
+
+impl PartialEq<char> for Character {
+    fn eq(&self, other: &char) -> bool {
+        self.as_char() == *other
+    }
+}
+
+impl PartialEq<str> for Character {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Character {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == **other
+    }
+}
+
+impl PartialEq<Character> for char {
+    fn eq(&self, other: &Character) -> bool {
+        *self == other.as_char()
+    }
+}
+
+impl PartialEq<Character> for str {
+    fn eq(&self, other: &Character) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<char> for Character {
+    fn partial_cmp(&self, other: &char) -> Option<cmp::Ordering> {
+        self.as_char().partial_cmp(other)
+    }
+}
+
+impl PartialOrd<str> for Character {
+    fn partial_cmp(&self, other: &str) -> Option<cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/// The error returned when a string is not exactly one [`Character`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CharacterError {
+    /// The string contained no codepoints.
+    Empty,
+    /// The string contained more than one codepoint.
+    TooLong,
+}
+
+impl<'a> TryFrom<&'a str> for &'a Character {
+    type Error = CharacterError;
+
+    fn try_from(s: &'a str) -> Result<Self, CharacterError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(CharacterError::Empty),
+            (Some(_), None) => Ok(unsafe { &*(s as *const str as *const Character) }),
+            (Some(_), Some(_)) => Err(CharacterError::TooLong),
+        }
+    }
+}
+
+/// A planned replacement of a range of a string, made in an indexing scope
+/// and applied to the owned string after the scope has ended.
+///
+/// The offsets are unbranded, but were vetted to be char boundaries when
+/// planned with [`Container::plan_replace`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct EditPlan<'a> {
+    pub(crate) range: ops::Range<u32>,
+    pub(crate) with: &'a str,
+}
+
+impl<'a> EditPlan<'a> {
+    /// The byte range to be removed.
+    pub fn range(&self) -> ops::Range<u32> {
+        self.range.clone()
+    }
+
+    /// The text to be inserted in place of the removed range.
+    pub fn replacement(&self) -> &'a str {
+        self.with
+    }
+
+    /// Apply this edit to the string it was planned for.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not on char boundaries of `s`, which is only
+    /// possible if `s` is not the string this edit was planned for.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+    pub fn apply(&self, s: &mut String) {
+        s.replace_range(
+            self.range.start as usize..self.range.end as usize,
+            self.with,
+        )
+    }
+}
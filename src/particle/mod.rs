@@ -1,8 +1,19 @@
 use {
     crate::{proof::*, traits::*, Container},
-    core::{convert::TryFrom, ops},
+    core::{
+        cmp,
+        convert::TryFrom,
+        fmt,
+        hash::{self, Hash},
+        marker::PhantomData,
+        mem::MaybeUninit,
+        ops,
+    },
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 pub mod perfect;
 pub mod simple;
 
@@ -15,6 +26,71 @@ pub enum IndexError {
     Invalid,
 }
 
+/// A particle or container tagged with a user marker type `Space`, to keep
+/// apart particles from different conceptual spaces of the same container,
+/// e.g. line indices and byte indices.
+///
+/// A tagged container (see [`Container::tagged`]) can only be indexed by
+/// particles with the same tag; untag a particle to use it directly.
+pub struct Tagged<Particle, Space> {
+    particle: Particle,
+    phantom: PhantomData<fn() -> Space>,
+}
+
+impl<Particle, Space> Tagged<Particle, Space> {
+    pub(crate) fn new(particle: Particle) -> Self {
+        Tagged {
+            particle,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The particle without the tag.
+    pub fn untag(self) -> Particle {
+        self.particle
+    }
+}
+
+impl<Particle: Copy, Space> Copy for Tagged<Particle, Space> {}
+
+impl<Particle: Clone, Space> Clone for Tagged<Particle, Space> {
+    fn clone(&self) -> Self {
+        Tagged::new(self.particle.clone())
+    }
+}
+
+impl<Particle: fmt::Debug, Space> fmt::Debug for Tagged<Particle, Space> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Tagged").field(&self.particle).finish()
+    }
+}
+
+impl<Particle: Eq, Space> Eq for Tagged<Particle, Space> {}
+
+impl<Particle: PartialEq, Space> PartialEq for Tagged<Particle, Space> {
+    fn eq(&self, other: &Self) -> bool {
+        self.particle == other.particle
+    }
+}
+
+impl<Particle: Ord, Space> Ord for Tagged<Particle, Space> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.particle.cmp(&other.particle)
+    }
+}
+
+impl<Particle: PartialOrd, Space> PartialOrd for Tagged<Particle, Space> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.particle.partial_cmp(&other.particle)
+    }
+}
+
+impl<Particle: Hash, Space> Hash for Tagged<Particle, Space> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.particle.hash(state)
+    }
+}
+
 /// A type that can be vetted against a trusted container to create a trusted particle.
 pub trait Vettable<'id> {
     type ContainerVetted;
@@ -87,9 +163,9 @@ impl<'id> Vettable<'id> for simple::Index<'id, NonEmpty> {
     }
 }
 
-impl<'id> Vettable<'id> for simple::Range<'id, Unknown> {
+impl<'id, Direction> Vettable<'id> for simple::Range<'id, Unknown, Direction> {
     type ContainerVetted = perfect::Range<'id, Unknown>;
-    type RangeVetted = simple::Range<'id, Unknown>;
+    type RangeVetted = simple::Range<'id, Unknown, Direction>;
 
     fn vet_in_container<Array: ?Sized>(
         self,
@@ -100,7 +176,7 @@ impl<'id> Vettable<'id> for simple::Range<'id, Unknown> {
     {
         let _end = Vettable::vet_in_container(self.end(), container)?;
         let _start = Vettable::vet_in_container(self.start(), container)?;
-        Ok(unsafe { perfect::Range::from(self) })
+        Ok(unsafe { perfect::Range::from(self.undirected()) })
     }
 
     fn vet_in_range<P>(
@@ -115,9 +191,9 @@ impl<'id> Vettable<'id> for simple::Range<'id, Unknown> {
     }
 }
 
-impl<'id> Vettable<'id> for simple::Range<'id, NonEmpty> {
+impl<'id, Direction> Vettable<'id> for simple::Range<'id, NonEmpty, Direction> {
     type ContainerVetted = perfect::Range<'id, NonEmpty>;
-    type RangeVetted = simple::Range<'id, NonEmpty>;
+    type RangeVetted = simple::Range<'id, NonEmpty, Direction>;
 
     fn vet_in_container<Array: ?Sized>(
         self,
@@ -128,7 +204,7 @@ impl<'id> Vettable<'id> for simple::Range<'id, NonEmpty> {
     {
         let _start = Vettable::vet_in_container(self.start(), container)?;
         let _end = Vettable::vet_in_container(self.end(), container)?;
-        Ok(unsafe { perfect::Range::from(self) })
+        Ok(unsafe { perfect::Range::from(self.undirected()) })
     }
 
     fn vet_in_range<P>(
@@ -296,3 +372,122 @@ macro_rules! vettable_int {
 }
 
 vettable_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A batch of particles that can be vetted against a trusted container
+/// at once, reporting which particle failed.
+pub trait BatchVettable<'id> {
+    type ContainerVetted;
+
+    fn vet_all_in_container<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<Self::ContainerVetted, (usize, IndexError)>
+    where
+        Array: TrustedContainer;
+}
+
+impl<'id, V, const N: usize> BatchVettable<'id> for [V; N]
+where
+    V: Vettable<'id>,
+{
+    type ContainerVetted = [V::ContainerVetted; N];
+
+    fn vet_all_in_container<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<Self::ContainerVetted, (usize, IndexError)>
+    where
+        Array: TrustedContainer,
+    {
+        let mut vetted = [(); N].map(|()| MaybeUninit::uninit());
+        for (position, particle) in IntoIterator::into_iter(self).enumerate() {
+            let particle = particle
+                .vet_in_container(container)
+                .map_err(|error| (position, error))?;
+            vetted[position] = MaybeUninit::new(particle);
+        }
+        // SAFETY: the loop ran to completion, so every element was written.
+        Ok(vetted.map(|particle| unsafe { particle.assume_init() }))
+    }
+}
+
+impl<'id, V, const N: usize> Vettable<'id> for [V; N]
+where
+    V: Vettable<'id>,
+{
+    type ContainerVetted = [V::ContainerVetted; N];
+    type RangeVetted = [V::RangeVetted; N];
+
+    fn vet_in_container<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<Self::ContainerVetted, IndexError>
+    where
+        Array: TrustedContainer,
+    {
+        self.vet_all_in_container(container)
+            .map_err(|(_, error)| error)
+    }
+
+    fn vet_in_range<P>(self, range: simple::Range<'id, P>) -> Option<Self::RangeVetted> {
+        let mut vetted = [(); N].map(|()| MaybeUninit::uninit());
+        for (position, particle) in IntoIterator::into_iter(self).enumerate() {
+            vetted[position] = MaybeUninit::new(particle.vet_in_range(range)?);
+        }
+        // SAFETY: the loop ran to completion, so every element was written.
+        Some(vetted.map(|particle| unsafe { particle.assume_init() }))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+impl<'id, V> BatchVettable<'id> for &'_ [V]
+where
+    V: Vettable<'id> + Copy,
+{
+    type ContainerVetted = Vec<V::ContainerVetted>;
+
+    fn vet_all_in_container<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<Self::ContainerVetted, (usize, IndexError)>
+    where
+        Array: TrustedContainer,
+    {
+        let mut vetted = Vec::with_capacity(self.len());
+        for (position, &particle) in self.iter().enumerate() {
+            let particle = particle
+                .vet_in_container(container)
+                .map_err(|error| (position, error))?;
+            vetted.push(particle);
+        }
+        Ok(vetted)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "alloc")))]
+impl<'id, V> Vettable<'id> for &'_ [V]
+where
+    V: Vettable<'id> + Copy,
+{
+    type ContainerVetted = Vec<V::ContainerVetted>;
+    type RangeVetted = Vec<V::RangeVetted>;
+
+    fn vet_in_container<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<Self::ContainerVetted, IndexError>
+    where
+        Array: TrustedContainer,
+    {
+        self.vet_all_in_container(container)
+            .map_err(|(_, error)| error)
+    }
+
+    fn vet_in_range<P>(self, range: simple::Range<'id, P>) -> Option<Self::RangeVetted> {
+        self.iter()
+            .map(|&particle| particle.vet_in_range(range))
+            .collect()
+    }
+}
@@ -1,6 +1,12 @@
 use {
-    crate::{particle::simple, proof::*},
+    crate::{
+        particle::{perfect::Range, simple, Tagged},
+        proof::*,
+        traits::*,
+        Container,
+    },
     core::{
+        borrow::Borrow,
         cmp,
         fmt::{self, Debug},
         hash::{self, Hash},
@@ -39,14 +45,46 @@ impl<'id, Emptiness> Index<'id, Emptiness> {
         unsafe { Index::from(self.simple.erased()) }
     }
 
+    /// This index tagged with the marker type `Space`.
+    pub fn tag<Space>(self) -> Tagged<Self, Space> {
+        Tagged::new(self)
+    }
+
     /// This index in simple manipulation mode.
     pub fn simple(self) -> simple::Index<'id, Emptiness> {
         self.simple
     }
 }
 
+/// Navigation
+impl<'id, Emptiness> Index<'id, Emptiness> {
+    /// The number of items from this index up to `other`,
+    /// or zero if `other` is before this index.
+    pub fn items_between<P, Array: ?Sized>(
+        self,
+        other: Index<'id, P>,
+        container: &Container<'id, Array>,
+    ) -> u32
+    where
+        Array: TrustedContainer,
+    {
+        if other.untrusted() <= self.untrusted() {
+            return 0;
+        }
+        let range =
+            unsafe { Range::<Unknown>::new(self.untrusted(), other.untrusted(), self.id()) };
+        range.item_count_in(container)
+    }
+}
+
 // ~~~ Standard traits ~~~ //
 
+impl<'id, Emptiness> Borrow<simple::Index<'id, Emptiness>> for Index<'id, Emptiness> {
+    fn borrow(&self) -> &simple::Index<'id, Emptiness> {
+        &self.simple
+    }
+}
+
 impl<'id, Emptiness> Copy for Index<'id, Emptiness> {}
 
 impl<'id, Emptiness> Clone for Index<'id, Emptiness> {
@@ -61,6 +99,8 @@ impl<'id, Emptiness> Debug for Index<'id, Emptiness> {
     }
 }
 
+// cannot be const: `generativity::Id::new` is not a `const fn`, and there is
+// no other way to produce an `Id<'id>` for an arbitrary brand
 impl<'id> Default for Index<'id, Unknown> {
     fn default() -> Self {
         unsafe { Index::new(0, generativity::Id::new()) }
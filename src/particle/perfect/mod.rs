@@ -11,3 +11,11 @@ mod index;
 mod range;
 
 pub use self::{index::Index, range::Range};
+
+use crate::proof::NonEmpty;
+
+/// A perfect index known to not be the one-past-the-end index.
+pub type NonEmptyIndex<'id> = Index<'id, NonEmpty>;
+
+/// A perfect range known to not be empty.
+pub type NonEmptyRange<'id> = Range<'id, NonEmpty>;
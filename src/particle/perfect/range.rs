@@ -1,14 +1,17 @@
 use {
     crate::{
-        particle::{perfect::Index, simple},
+        particle::{perfect::Index, simple, Tagged},
         proof::*,
+        traits::*,
+        Container,
     },
     core::{
+        borrow::Borrow,
         cmp,
         convert::{TryFrom, TryInto},
         fmt::{self, Debug},
         hash::{self, Hash},
-        ops,
+        iter, ops,
     },
 };
 
@@ -52,6 +55,11 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
         unsafe { Range::from(self.simple.erased()) }
     }
 
+    /// This range tagged with the marker type `Space`.
+    pub fn tag<Space>(self) -> Tagged<Self, Space> {
+        Tagged::new(self)
+    }
+
     /// This range with a proof of non-emptiness.
     pub fn nonempty(self) -> Option<Range<'id, NonEmpty>> {
         if !self.is_empty() {
@@ -61,6 +69,14 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
         }
     }
 
+    /// This range encoded as a single unbranded integer, with the start
+    /// in the high 32 bits and the end in the low 32 bits.
+    ///
+    /// Use [`Container::detoken`] to vet it back into a range.
+    pub fn token(self) -> u64 {
+        u64::from(self.start().untrusted()) << 32 | u64::from(self.end().untrusted())
+    }
+
     /// This range in simple manipulation mode.
     pub fn simple(self) -> simple::Range<'id, Emptiness> {
         self.simple
@@ -93,6 +109,31 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     pub fn contains<P>(self, index: Index<'id, P>) -> bool {
         self.start() <= index && index < self.end()
     }
+
+    /// The number of items in this range.
+    pub fn item_count_in<Array: ?Sized>(self, container: &Container<'id, Array>) -> u32
+    where
+        Array: TrustedContainer,
+    {
+        self.step_by_in(1, container).count() as u32
+    }
+
+    /// The index of the sole item in this range,
+    /// or `None` if it is empty or covers more than one item.
+    pub fn single<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Option<Index<'id, NonEmpty>>
+    where
+        Array: TrustedContainer,
+    {
+        let first = self.nonempty()?.start();
+        if container.after(first) == self.end() {
+            Some(first)
+        } else {
+            None
+        }
+    }
 }
 
 /// Manipulation
@@ -166,10 +207,42 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     pub fn frontiers(self) -> (Range<'id, Unknown>, Range<'id, Unknown>) {
         (Range::singleton(self.start()), Range::singleton(self.end()))
     }
+
+    /// The index of every `n`th item in this range, starting with the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn step_by_in<'a, Array: ?Sized>(
+        self,
+        n: u32,
+        container: &'a Container<'id, Array>,
+    ) -> impl Iterator<Item = Index<'id, NonEmpty>> + 'a
+    where
+        Array: TrustedContainer,
+    {
+        assert!(n != 0, "step_by_in with zero step");
+        let end = self.end();
+        let first = self.nonempty().map(Range::start);
+        iter::successors(first, move |&ix| {
+            let next = (0..n).try_fold(ix, |ix, _| container.advance(ix))?;
+            if next < end {
+                Some(next)
+            } else {
+                None
+            }
+        })
+    }
 }
 
 // ~~~ Standard traits ~~~ //
 
+impl<'id, Emptiness> Borrow<simple::Range<'id, Emptiness>> for Range<'id, Emptiness> {
+    fn borrow(&self) -> &simple::Range<'id, Emptiness> {
+        &self.simple
+    }
+}
+
 impl<'id, Emptiness> Copy for Range<'id, Emptiness> {}
 
 impl<'id, Emptiness> Clone for Range<'id, Emptiness> {
@@ -1,5 +1,8 @@
 use {
-    crate::{particle::perfect, proof::*},
+    crate::{
+        particle::{perfect, Tagged},
+        proof::*,
+    },
     core::{
         cmp,
         fmt::{self, Debug},
@@ -41,6 +44,24 @@ impl<'id, Emptiness> Index<'id, Emptiness> {
     pub fn erased(self) -> Index<'id, Unknown> {
         unsafe { Index::new(self.ix, self.id) }
     }
+
+    /// This index tagged with the marker type `Space`.
+    pub fn tag<Space>(self) -> Tagged<Self, Space> {
+        Tagged::new(self)
+    }
+}
+
+/// Manipulation
+impl<'id, Emptiness> Index<'id, Emptiness> {
+    /// The earlier of this index and `other`.
+    pub fn min<Q>(self, other: Index<'id, Q>) -> Index<'id, Unknown> {
+        cmp::min(self.erased(), other.erased())
+    }
+
+    /// The later of this index and `other`.
+    pub fn max<Q>(self, other: Index<'id, Q>) -> Index<'id, Unknown> {
+        cmp::max(self.erased(), other.erased())
+    }
 }
 
 /// Manipulation
@@ -73,6 +94,8 @@ impl<'id, Emptiness> Debug for Index<'id, Emptiness> {
     }
 }
 
+// cannot be const: `generativity::Id::new` is not a `const fn`, and there is
+// no other way to produce an `Id<'id>` for an arbitrary brand
 impl<'id> Default for Index<'id, Unknown> {
     fn default() -> Self {
         unsafe { Self::new(0, generativity::Id::new()) }
@@ -12,3 +12,11 @@ mod index;
 mod range;
 
 pub use self::{index::Index, range::Range};
+
+use crate::proof::NonEmpty;
+
+/// A simple index known to not be the one-past-the-end index.
+pub type NonEmptyIndex<'id> = Index<'id, NonEmpty>;
+
+/// A simple range known to not be empty.
+pub type NonEmptyRange<'id> = Range<'id, NonEmpty>;
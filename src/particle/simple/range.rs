@@ -1,7 +1,9 @@
 use {
     crate::{
-        particle::{perfect, simple::Index, Vettable},
+        particle::{perfect, simple::Index, IndexError, Vettable},
         proof::*,
+        traits::*,
+        Container,
     },
     core::{
         cmp,
@@ -13,14 +15,14 @@ use {
     },
 };
 
-pub struct Range<'id, Emptiness = Unknown> {
+pub struct Range<'id, Emptiness = Unknown, Direction = Undirected> {
     start: Index<'id, Unknown>,
     end: Index<'id, Unknown>,
-    phantom: PhantomData<Emptiness>,
+    phantom: PhantomData<(Emptiness, Direction)>,
 }
 
 /// Constructors
-impl<'id, Emptiness> Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Range<'id, Emptiness, Direction> {
     pub(crate) unsafe fn new(start: u32, end: u32, guard: generativity::Id<'id>) -> Self {
         debug_assert!(start <= end);
         Range {
@@ -44,14 +46,14 @@ impl<'id> Range<'id, Unknown> {
 }
 
 /// Proof manipulation
-impl<'id, Emptiness> Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Range<'id, Emptiness, Direction> {
     /// This range without the brand.
     pub fn untrusted(self) -> ops::Range<u32> {
         self.start.untrusted()..self.end.untrusted()
     }
 
     /// This range without the emptiness proof.
-    pub fn erased(self) -> Range<'id, Unknown> {
+    pub fn erased(self) -> Range<'id, Unknown, Direction> {
         unsafe {
             Range::new(
                 self.start.untrusted(),
@@ -62,17 +64,22 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     }
 
     /// This range with a proof of non-emptiness.
-    pub fn nonempty(self) -> Option<Range<'id, NonEmpty>> {
+    pub fn nonempty(self) -> Option<Range<'id, NonEmpty, Direction>> {
         if !self.is_empty() {
             Some(unsafe { Range::new(self.start().untrusted(), self.end().untrusted(), self.id()) })
         } else {
             None
         }
     }
+
+    /// This range without the direction marker.
+    pub fn undirected(self) -> Range<'id, Emptiness> {
+        unsafe { Range::new(self.start().untrusted(), self.end().untrusted(), self.id()) }
+    }
 }
 
 /// Intrinsic properties
-impl<'id, Emptiness> Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Range<'id, Emptiness, Direction> {
     /// The start index of this range.
     pub fn start(self) -> Index<'id, Emptiness> {
         unsafe { Index::new(self.start.untrusted(), self.id()) }
@@ -98,9 +105,19 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
         self.start() <= index && index < self.end()
     }
 
+    /// Do these ranges share at least one unit?
+    ///
+    /// Empty ranges never overlap anything.
+    pub fn overlaps<Q, D>(self, other: Range<'id, Q, D>) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.start() < other.end()
+            && other.start() < self.end()
+    }
+
     /// Vet a particle for being within this range.
     pub fn vet<V: Vettable<'id>>(self, particle: V) -> Option<V::RangeVetted> {
-        particle.vet_in_range(self)
+        particle.vet_in_range(self.undirected())
     }
 
     /// Vet an index for being in this range or the one-past-the-end index.
@@ -113,12 +130,44 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     }
 }
 
+/// Upgrade
+impl<'id, Emptiness, Direction> Range<'id, Emptiness, Direction> {
+    /// Upgrade this range to a perfect range by vetting both endpoints
+    /// as item boundaries of the container, preserving the emptiness proof.
+    pub fn perfect_in<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Result<perfect::Range<'id, Emptiness>, IndexError>
+    where
+        Array: TrustedContainer,
+    {
+        let _start = Array::Item::vet(self.start().untrusted(), container)?;
+        let _end = Array::Item::vet(self.end().untrusted(), container)?;
+        Ok(unsafe { perfect::Range::from(self.undirected()) })
+    }
+
+    /// Upgrade this range to a perfect range without checking it.
+    ///
+    /// # Safety
+    ///
+    /// Both the start and end of this range must be item boundaries of the
+    /// container branded with `'id`; i.e. [`perfect_in`](`Range::perfect_in`) would succeed.
+    /// For unit containers such as `[T]`, every in-bounds simple range is
+    /// perfect; for `str`, both ends must be char boundaries.
+    pub unsafe fn assume_perfect(self) -> perfect::Range<'id, Emptiness> {
+        perfect::Range::from(self.undirected())
+    }
+}
+
 /// Manipulation
-impl<'id, Emptiness> Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Range<'id, Emptiness, Direction> {
     /// Split this range at an index, if that index is in the range.
     ///
     /// The given index is contained in the second range.
-    pub fn split_at<P>(self, index: Index<'id, P>) -> Option<(Range<'id>, Range<'id, P>)> {
+    pub fn split_at<P>(
+        self,
+        index: Index<'id, P>,
+    ) -> Option<(Range<'id, Unknown, Direction>, Range<'id, P, Direction>)> {
         if self.start() <= index && index <= self.end() {
             unsafe {
                 Some((
@@ -136,8 +185,8 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     /// (They must be exactly touching, in left-to-right order.)
     pub fn join<P>(
         self,
-        other: Range<'id, P>,
-    ) -> Option<Range<'id, <(Emptiness, P) as ProofAdd>::Sum>>
+        other: Range<'id, P, Direction>,
+    ) -> Option<Range<'id, <(Emptiness, P) as ProofAdd>::Sum, Direction>>
     where
         (Emptiness, P): ProofAdd,
     {
@@ -154,12 +203,35 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
         }
     }
 
+    /// Join this range with each of `rest` in turn, as by repeated
+    /// [`join`](`Range::join`). Returns `None` if any two consecutive
+    /// ranges are not exactly adjacent.
+    pub fn join_all<I>(self, rest: I) -> Option<Range<'id, Emptiness, Direction>>
+    where
+        I: IntoIterator<Item = Range<'id, Unknown, Direction>>,
+    {
+        let mut end = self.end();
+        for range in rest {
+            if end != range.start() {
+                return None;
+            }
+            end = range.end();
+        }
+        unsafe {
+            Some(Range::new(
+                self.start().untrusted(),
+                end.untrusted(),
+                self.id(),
+            ))
+        }
+    }
+
     /// Extend this range to cover both itself and `other`,
     /// including any space inbetween.
     pub fn join_cover<P>(
         self,
-        other: Range<'id, P>,
-    ) -> Range<'id, <(Emptiness, P) as ProofAdd>::Sum>
+        other: Range<'id, P, Direction>,
+    ) -> Range<'id, <(Emptiness, P) as ProofAdd>::Sum, Direction>
     where
         (Emptiness, P): ProofAdd,
     {
@@ -169,13 +241,13 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     }
 
     /// Extend the end of this range to the given index.
-    pub fn extend_end<P>(self, index: Index<'id, P>) -> Range<'id, Emptiness> {
+    pub fn extend_end<P>(self, index: Index<'id, P>) -> Range<'id, Emptiness, Direction> {
         let end = cmp::max(self.end().erased(), index.erased());
         unsafe { Range::new(self.start().untrusted(), end.untrusted(), self.id()) }
     }
 
     /// Extend the start of this range to the given index.
-    pub fn extend_start<P>(self, index: Index<'id, P>) -> Range<'id, Emptiness> {
+    pub fn extend_start<P>(self, index: Index<'id, P>) -> Range<'id, Emptiness, Direction> {
         let start = cmp::min(self.start().erased(), index.erased());
         unsafe { Range::new(start.untrusted(), self.end().untrusted(), self.id()) }
     }
@@ -184,6 +256,211 @@ impl<'id, Emptiness> Range<'id, Emptiness> {
     pub fn frontiers(self) -> (Range<'id, Unknown>, Range<'id, Unknown>) {
         (Range::singleton(self.start()), Range::singleton(self.end()))
     }
+
+    /// The empty range at the start and end of this range,
+    /// marked with the side of this range they were taken from.
+    pub fn directed_frontiers(self) -> (Range<'id, Unknown, Front>, Range<'id, Unknown, Back>) {
+        unsafe {
+            (
+                Range::new(
+                    self.start().untrusted(),
+                    self.start().untrusted(),
+                    self.id(),
+                ),
+                Range::new(self.end().untrusted(), self.end().untrusted(), self.id()),
+            )
+        }
+    }
+
+    /// This range without its first item, or `None` if it is empty.
+    pub fn advance_in<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Option<Range<'id, Unknown, Direction>>
+    where
+        Array: TrustedContainer,
+    {
+        let first = container.align_inbounds(self.nonempty()?.start());
+        let start = cmp::min(container.after(first).untrusted(), self.end().untrusted());
+        Some(unsafe { Range::new(start, self.end().untrusted(), self.id()) })
+    }
+
+    /// This range without its last item, or `None` if it is empty.
+    pub fn retreat_in<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Option<Range<'id, Unknown, Direction>>
+    where
+        Array: TrustedContainer,
+    {
+        let last = self.nonempty()?.end().untrusted() - 1;
+        let last = container.align_inbounds(unsafe { Index::<NonEmpty>::new(last, self.id()) });
+        let end = cmp::max(last.untrusted(), self.start().untrusted());
+        Some(unsafe { Range::new(self.start().untrusted(), end, self.id()) })
+    }
+
+    /// Clamp this range to the bounds of the container,
+    /// snapping both ends down to item boundaries.
+    pub fn clamp_to<Array: ?Sized>(self, container: &Container<'id, Array>) -> Range<'id, Unknown>
+    where
+        Array: TrustedContainer,
+    {
+        let end = cmp::min(self.end().untrusted(), container.len());
+        let start = cmp::min(self.start().untrusted(), end);
+        let start = container.align_inbounds(unsafe { Index::<Unknown>::new(start, self.id()) });
+        let end = container.align_inbounds(unsafe { Index::<Unknown>::new(end, self.id()) });
+        unsafe { Range::new(start.untrusted(), end.untrusted(), self.id()) }
+    }
+
+    /// Shrink this range to the largest range of whole items within it,
+    /// snapping the start up and the end down to item boundaries.
+    ///
+    /// If no whole item fits, the result is empty. If no item boundary lies
+    /// within this range at all, there is no such range, and this returns
+    /// `None`.
+    pub fn snap_inward<Array: ?Sized>(
+        self,
+        container: &Container<'id, Array>,
+    ) -> Option<perfect::Range<'id, Unknown>>
+    where
+        Array: TrustedContainer,
+    {
+        let end = container.align_inbounds(self.end());
+        let start = match container.vet(self.start().erased()) {
+            Ok(start) => start.erased(),
+            Err(_) if self.start().untrusted() == container.len() => container.end(),
+            Err(_) => container.after(container.align_inbounds(unsafe {
+                Index::<NonEmpty>::new(self.start().untrusted(), self.id())
+            })),
+        };
+        if start > end {
+            return None;
+        }
+        Some(unsafe { perfect::Range::new(start.untrusted(), end.untrusted(), self.id()) })
+    }
+
+    /// Split this range at `numerator / denominator` of its length,
+    /// snapping the split point down to an item boundary.
+    ///
+    /// The split point is kept before the last item of a nonempty range,
+    /// so the second range retains the emptiness proof of this range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn split_at_ratio<Array: ?Sized>(
+        self,
+        numerator: u32,
+        denominator: u32,
+        container: &Container<'id, Array>,
+    ) -> (Range<'id, Unknown>, Range<'id, Emptiness>)
+    where
+        Array: TrustedContainer,
+    {
+        assert!(denominator != 0, "split_at_ratio with zero denominator");
+        let start = self.start().untrusted();
+        let mid = match self.nonempty() {
+            Some(this) => {
+                let offset = u64::from(this.len()) * u64::from(numerator) / u64::from(denominator);
+                let mid = cmp::min(
+                    u64::from(start) + offset,
+                    u64::from(this.end().untrusted() - 1),
+                );
+                let mid = unsafe { Index::<NonEmpty>::new(mid as u32, self.id()) };
+                cmp::max(container.align_inbounds(mid).untrusted(), start)
+            }
+            None => start,
+        };
+        unsafe {
+            (
+                Range::new(start, mid, self.id()),
+                Range::new(mid, self.end().untrusted(), self.id()),
+            )
+        }
+    }
+
+    /// Split this range into a head, a middle whose start and length are
+    /// multiples of `align`, and a tail, as `slice::align_to` in index space.
+    ///
+    /// Alignment is measured in units from the start of the container.
+    /// If no aligned middle fits, the head covers the whole range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    pub fn align_to<Array: ?Sized>(
+        self,
+        align: u32,
+        _container: &Container<'id, Array>,
+    ) -> (
+        Range<'id, Unknown>,
+        Range<'id, Unknown>,
+        Range<'id, Unknown>,
+    )
+    where
+        Array: TrustedContainer,
+        Array::Item: TrustedUnit<Array>,
+    {
+        assert!(align.is_power_of_two(), "align_to with non power of two");
+        let start = self.start().untrusted();
+        let end = self.end().untrusted();
+        let aligned = (u64::from(start) + u64::from(align - 1)) & !u64::from(align - 1);
+        let mid_start = cmp::min(aligned, u64::from(end)) as u32;
+        let mid_end = mid_start + ((end - mid_start) & !(align - 1));
+        let mid_start = if mid_start == mid_end { end } else { mid_start };
+        let mid_end = if mid_start == end { end } else { mid_end };
+        unsafe {
+            (
+                Range::new(start, mid_start, self.id()),
+                Range::new(mid_start, mid_end, self.id()),
+                Range::new(mid_end, end, self.id()),
+            )
+        }
+    }
+
+    /// Split this range at the partition point of `pred`, as by
+    /// `slice::partition_point`: the first range holds the items
+    /// for which `pred` returns true, and the second range the rest.
+    ///
+    /// If `pred` is not monotone over this range, the split point is
+    /// unspecified, but both ranges are still within this range.
+    pub fn bisect_by<Array: ?Sized, F>(
+        self,
+        container: &Container<'id, Array>,
+        mut pred: F,
+    ) -> (Range<'id, Unknown>, Range<'id, Unknown>)
+    where
+        Array: TrustedContainer,
+        Array::Item: TrustedUnit<Array>,
+        F: FnMut(&Array::Item) -> bool,
+    {
+        let mut lo = self.start().untrusted();
+        let mut hi = self.end().untrusted();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let ix = unsafe { perfect::Index::<NonEmpty>::new(mid, self.id()) };
+            if pred(&container[ix]) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        unsafe {
+            (
+                Range::new(self.start().untrusted(), lo, self.id()),
+                Range::new(lo, self.end().untrusted(), self.id()),
+            )
+        }
+    }
+}
+
+/// Manipulation
+impl<'id, Direction> Range<'id, NonEmpty, Direction> {
+    /// The index `n` units into this range, wrapping around to the start
+    /// as if this range were a ring.
+    pub fn wrapping_index(self, n: u32) -> Index<'id, NonEmpty> {
+        unsafe { Index::new(self.start().untrusted() + n % self.len(), self.id()) }
+    }
 }
 
 // ~~~ Standard traits ~~~ //
@@ -194,15 +471,15 @@ impl<'id, Emptiness> From<perfect::Range<'id, Emptiness>> for Range<'id, Emptine
     }
 }
 
-impl<'id, Emptiness> Copy for Range<'id, Emptiness> {}
+impl<'id, Emptiness, Direction> Copy for Range<'id, Emptiness, Direction> {}
 
-impl<'id, Emptiness> Clone for Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Clone for Range<'id, Emptiness, Direction> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<'id, Emptiness> Debug for Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Debug for Range<'id, Emptiness, Direction> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("simple::Range<'id>").finish()
     }
@@ -214,21 +491,25 @@ impl<'id> Default for Range<'id, Unknown> {
     }
 }
 
-impl<'id, Emptiness> Eq for Range<'id, Emptiness> {}
+impl<'id, Emptiness, Direction> Eq for Range<'id, Emptiness, Direction> {}
 
-impl<'id, 'jd, Emptiness, P> PartialEq<Range<'jd, P>> for Range<'id, Emptiness> {
-    fn eq(&self, other: &Range<'jd, P>) -> bool {
+impl<'id, 'jd, Emptiness, Direction, P, D> PartialEq<Range<'jd, P, D>>
+    for Range<'id, Emptiness, Direction>
+{
+    fn eq(&self, other: &Range<'jd, P, D>) -> bool {
         self.start.eq(&other.start) && self.end.eq(&other.end)
     }
 }
 
-impl<'id, 'jd, Emptiness, P> PartialEq<perfect::Range<'jd, P>> for Range<'id, Emptiness> {
+impl<'id, 'jd, Emptiness, Direction, P> PartialEq<perfect::Range<'jd, P>>
+    for Range<'id, Emptiness, Direction>
+{
     fn eq(&self, other: &perfect::Range<'jd, P>) -> bool {
         self.eq(&other.simple())
     }
 }
 
-impl<'id, Emptiness> Hash for Range<'id, Emptiness> {
+impl<'id, Emptiness, Direction> Hash for Range<'id, Emptiness, Direction> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.start.hash(state);
         self.end.hash(state);
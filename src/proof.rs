@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 /// Length marker for range/index known to not be empty.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum NonEmpty {}
@@ -6,6 +8,40 @@ pub enum NonEmpty {}
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum Unknown {}
 
+/// Direction marker for a range with no particular provenance.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Undirected {}
+
+/// Direction marker for a range anchored at the front of the range it was
+/// produced from, i.e. a remaining prefix.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Front {}
+
+/// Direction marker for a range anchored at the back of the range it was
+/// produced from, i.e. a remaining suffix.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Back {}
+
+/// Witness that the items of the container branded `'id` are sorted.
+///
+/// The witness borrows the container for `'a`, so the container cannot be
+/// mutated while it is alive.
+#[derive(Copy, Clone, Debug)]
+pub struct Sorted<'a, 'id> {
+    #[allow(unused)]
+    id: generativity::Id<'id>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, 'id> Sorted<'a, 'id> {
+    pub(crate) unsafe fn new(id: generativity::Id<'id>) -> Self {
+        Sorted {
+            id,
+            phantom: PhantomData,
+        }
+    }
+}
+
 /// Represents the combination of two proofs `P` and `Q` by a new type `Sum`.
 pub trait ProofAdd {
     type Sum;
@@ -28,6 +28,38 @@ pub unsafe trait TrustedContainerMut: TrustedContainer {
     unsafe fn slice_unchecked_mut(&mut self, r: ops::Range<u32>) -> &mut Self::Slice;
 }
 
+/// A [`TrustedContainerMut`] that gives mutable access to its
+/// representational units.
+///
+/// # Safety
+///
+/// The units must be exactly the units that the container's particles
+/// index, i.e. there must be [`len`](`TrustedContainer::len`) of them.
+pub unsafe trait TrustedUnitsMut: TrustedContainerMut {
+    /// The representational units of the container.
+    ///
+    /// # Safety
+    ///
+    /// The units must be left forming a valid container, e.g. valid UTF-8.
+    unsafe fn units_mut(&mut self) -> &mut [<Self::Item as TrustedItem<Self>>::Unit];
+}
+
+/// A [`TrustedContainerMut`] that can be split into two disjoint mutable
+/// slices.
+///
+/// # Safety
+///
+/// The two slices must not overlap, and must be the slices of the container
+/// before and after the split index.
+pub unsafe trait TrustedSplitMut: TrustedContainerMut {
+    /// Split the container into two disjoint mutable slices at `at`.
+    ///
+    /// # Safety
+    ///
+    /// `at` must be a valid index of the container, including the end index.
+    unsafe fn split_unchecked_mut(&mut self, at: u32) -> (&mut Self::Slice, &mut Self::Slice);
+}
+
 /// An item within a [`TrustedContainer`].
 ///
 /// Note that raw indices are _unit_ indices, not item indices. One item (e.g.
@@ -67,6 +99,30 @@ where
         ix: u32,
         container: &Container<'id, Array>,
     ) -> Option<Index<'id, NonEmpty>>;
+
+    /// The index directly after the item at the given index.
+    ///
+    /// The default implementation scans forward for the next item boundary.
+    fn after<'id>(
+        ix: Index<'id, NonEmpty>,
+        container: &Container<'id, Array>,
+    ) -> Index<'id, Unknown> {
+        (ix.untrusted() + 1..container.len())
+            .find_map(|i| unsafe { Self::vet_inbounds(i, container) })
+            .map_or_else(|| container.end(), Index::erased)
+    }
+
+    /// The index of the item directly before the given index, if any.
+    ///
+    /// The default implementation scans backward for the previous item boundary.
+    fn before<'id, P>(
+        ix: Index<'id, P>,
+        container: &Container<'id, Array>,
+    ) -> Option<Index<'id, NonEmpty>> {
+        (0..ix.untrusted())
+            .rev()
+            .find_map(|i| unsafe { Self::vet_inbounds(i, container) })
+    }
 }
 
 /// A [`TrustedItem`] where the item is the base unit. Thus, manipulating
@@ -77,3 +133,59 @@ where
     Array: TrustedContainer<Item = Self>,
 {
 }
+
+/// A [`TrustedItem`] made of bytes, whose boundaries do not depend on the
+/// case of ASCII bytes, e.g. `u8` in `[u8]` or `Character` in `str`.
+///
+/// # Safety
+///
+/// Converting any ASCII byte of the container's units to upper or lower
+/// case must leave every item boundary of the container in place.
+pub unsafe trait TrustedAsciiCase<Array: ?Sized>: TrustedItem<Array, Unit = u8>
+where
+    Array: TrustedContainer<Item = Self>,
+{
+}
+
+/// A [`TrustedContainer`] that can be grown in place.
+///
+/// # Safety
+///
+/// Growing the container must only append items to the end; every existing
+/// index must remain valid and refer to the same item as before.
+///
+/// The container must not be `Copy`. A `Container` of a `Copy` array can be
+/// cloned, and the clone shares the brand; growing one of the two would then
+/// produce indices that are out of bounds for the other.
+pub unsafe trait GrowableContainer: TrustedContainer {
+    /// The owned form of an item, which can be appended to the container.
+    type OwnedItem;
+
+    /// Append an item to the end of the container.
+    fn push(&mut self, item: Self::OwnedItem);
+
+    /// Shorten the container to `len` base representation units.
+    ///
+    /// This has no effect if `len` is not less than the current length.
+    fn truncate(&mut self, len: u32);
+}
+
+/// A [`TrustedContainer`] that can be viewed as a borrowed container with the
+/// same indices, e.g. `String` as `str` or `Vec<T>` as `[T]`.
+///
+/// # Safety
+///
+/// The projection must refer to the same data: it must have the same length,
+/// and every particle valid for the container must be valid for the
+/// projection and refer to the same item.
+pub unsafe trait Projectable: TrustedContainer {
+    /// The borrowed container type this container projects to.
+    type Target: ?Sized + TrustedContainer;
+
+    /// View this container as its projection.
+    fn project(&self) -> &Self::Target;
+}
+
+/// Lets an `impl Trait` return type capture the lifetime `'a` without
+/// mentioning it anywhere else [rust-lang/rust#34511].
+pub trait Captures<'a> {}
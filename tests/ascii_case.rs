@@ -0,0 +1,26 @@
+use windex::scope_val;
+
+#[test]
+fn ascii_case_in_str() {
+    scope_val(&mut *String::from("aébcd"), |mut s| {
+        let r = s.vet(1u32..5).unwrap().simple();
+        s.make_ascii_uppercase_in(r);
+        assert_eq!(&s[..], "aéBCd");
+        let all = s.as_range().simple();
+        s.make_ascii_lowercase_in(all);
+        assert_eq!(&s[..], "aébcd");
+    });
+}
+
+#[test]
+fn ascii_case_in_bytes() {
+    let mut bytes = *b"ab\xffcd";
+    scope_val(&mut bytes[..], |mut s| {
+        let r = s.vet(1u32..4).unwrap().simple();
+        s.make_ascii_uppercase_in(r);
+        assert_eq!(&s[..], b"aB\xffCd");
+        let all = s.as_range().simple();
+        s.make_ascii_lowercase_in(all);
+        assert_eq!(&s[..], b"ab\xffcd");
+    });
+}
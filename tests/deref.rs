@@ -0,0 +1,83 @@
+use windex::{scope_val, Container};
+
+#[test]
+fn string_shares_brand_with_str() {
+    scope_val(String::from("héllo"), |s| {
+        let borrowed: &Container<'_, str> = &s;
+
+        let range = s.vet(1..3).unwrap();
+        assert_eq!(&borrowed[range], "é");
+
+        let range = borrowed.vet(3..).unwrap();
+        assert_eq!(&s[range], "llo");
+
+        let ix = s.vet(1u32).unwrap();
+        assert_eq!(borrowed[ix].as_char(), 'é');
+    })
+}
+
+#[test]
+fn vec_shares_brand_with_slice() {
+    scope_val(vec![0, 1, 2, 3], |v| {
+        let slice: &Container<'_, [i32]> = &v;
+
+        let range = v.vet(1..3).unwrap();
+        assert_eq!(&slice[range], &[1, 2]);
+
+        let ix = slice.vet(3u32).unwrap();
+        assert_eq!(v[ix], 3);
+    })
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn string_projects_to_str_with_same_brand() {
+    scope_val(String::from("héllo"), |s| {
+        let projected: &Container<'_, str> = s.project();
+
+        let range = s.vet(1..3).unwrap();
+        assert_eq!(&projected[range], "é");
+
+        let range = projected.vet(3..).unwrap();
+        assert_eq!(&s[range], "llo");
+
+        let ix = s.vet(1u32).unwrap();
+        assert_eq!(projected[ix].as_char(), 'é');
+    })
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec_projects_to_slice_with_same_brand() {
+    scope_val(vec![0, 1, 2, 3], |v| {
+        let projected: &Container<'_, [i32]> = v.project();
+
+        let range = v.vet(1..3).unwrap();
+        assert_eq!(&projected[range], &[1, 2]);
+
+        let ix = projected.vet(3u32).unwrap();
+        assert_eq!(v[ix], 3);
+    })
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_str_projects_to_str_with_same_brand() {
+    scope_val(Box::<str>::from("héllo"), |s| {
+        let projected: &Container<'_, str> = s.project();
+        let ix = projected.vet(1u32).unwrap();
+        assert_eq!(s[ix].as_char(), 'é');
+        assert_eq!(projected.len(), s.len());
+    })
+}
+
+#[test]
+fn reference_projects_to_referent_with_same_brand() {
+    let array = [0, 1, 2, 3];
+    scope_val(&array[..], |v| {
+        let projected: &Container<'_, [i32]> = v.project();
+
+        let range = projected.vet(2..).unwrap();
+        assert_eq!(&v[range], &[2, 3]);
+    })
+}
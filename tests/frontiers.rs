@@ -0,0 +1,49 @@
+use windex::{particle::simple, proof::Front, scope, scope_val};
+
+#[test]
+fn directed_ranges_index_and_vet() {
+    scope(&[1, 2, 3, 4][..], |s| {
+        let r = s.vet(1u32..3).unwrap().simple();
+        let (front, back) = r.directed_frontiers();
+        assert!(s[front].is_empty());
+        assert!(s[back].is_empty());
+        assert_eq!(s.vet(front).unwrap().untrusted(), 1..1);
+        assert_eq!(s.as_range().simple().vet(back).unwrap().untrusted(), 3..3);
+    });
+    scope("aéb", |s| {
+        let r = s.vet(1u32..3).unwrap().simple();
+        let (front, back) = r.directed_frontiers();
+        assert_eq!(&s[s.vet(front).unwrap()], "");
+        assert_eq!(s.vet(back).unwrap().untrusted(), 3..3);
+    });
+}
+
+#[test]
+fn frontiers_stay_undirected() {
+    scope(&[1, 2, 3][..], |s| {
+        let (front, back): (simple::Range<'_>, simple::Range<'_>) =
+            s.as_range().simple().frontiers();
+        assert_eq!(front.untrusted(), 0..0);
+        assert_eq!(back.untrusted(), 3..3);
+    });
+}
+
+#[test]
+fn directed_ranges_in_container_methods() {
+    scope_val(vec![1, 2, 1, 2], |mut s| {
+        let (front, _) = s.as_range().simple().directed_frontiers();
+        let a = front.extend_end(s.vet(2u32).unwrap().simple());
+        let (_, b) = s.as_range().simple().directed_frontiers();
+        let b = b.extend_start(s.vet(2u32).unwrap().simple());
+        assert!(s.range_eq(a, b));
+        let (head, tail) = a.split_at(s.vet(1u32).unwrap().simple()).unwrap();
+        let joined: simple::Range<'_, _, Front> = head.join(tail).unwrap();
+        assert_eq!(joined.untrusted(), 0..2);
+        s.copy_from_slice(b, &[3, 4]);
+        assert_eq!(
+            s.binary_search_in_by(b, |x| x.cmp(&4))
+                .map(|ix| ix.untrusted()),
+            Ok(3)
+        );
+    });
+}
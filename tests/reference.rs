@@ -0,0 +1,49 @@
+use windex::{scope, scope_val, Container};
+
+#[test]
+fn as_ref_shares_brand() {
+    scope("héllo", |s| {
+        let r: Container<'_, &str> = s.as_ref();
+        assert_eq!(r.len(), s.len());
+
+        let range = s.vet(1..3).unwrap();
+        assert_eq!(&r[range], "é");
+
+        let ix = r.vet(3u32).unwrap();
+        assert_eq!(s[ix].as_char(), 'l');
+    })
+}
+
+#[test]
+fn as_ref_of_reference_container() {
+    let v = [0, 1, 2, 3];
+    scope_val(&v[..], |s| {
+        let r: Container<'_, &&[i32]> = s.as_ref();
+        let range = r.vet(1..3).unwrap();
+        assert_eq!(&s[range], &[1, 2]);
+        assert_eq!(&r[range], &[1, 2]);
+    })
+}
+
+#[test]
+fn as_ref_mut_writes_through() {
+    let mut v = [0, 1, 2, 3];
+    scope_val(&mut v[..], |mut s| {
+        let ix = s.vet(2u32).unwrap();
+        {
+            let mut r: Container<'_, &mut &mut [i32]> = s.as_ref_mut();
+            r[ix] = 20;
+        }
+        assert_eq!(s[ix], 20);
+    });
+    assert_eq!(v, [0, 1, 20, 3]);
+}
+
+#[test]
+fn as_ref_mut_of_owned_container() {
+    scope_val(String::from("abc"), |mut s| {
+        let range = s.vet(1..).unwrap();
+        let r = s.as_ref_mut();
+        assert_eq!(&r[range], "bc");
+    })
+}
@@ -0,0 +1,29 @@
+use windex::scope;
+
+#[test]
+fn snap_inward_to_whole_items() {
+    scope("aéöb", |s| {
+        let r = s.vet(0u32..5).unwrap().simple();
+        let (_, r) = r.split_at(r.vet(2).unwrap()).unwrap();
+        // 2..5 covers the second byte of é, all of ö
+        assert_eq!(&s[r.snap_inward(s).unwrap()], "ö");
+        // 2..3 ends on the boundary after é
+        let r = s.vet(1u32..3).unwrap().simple();
+        let (_, r) = r.split_at(r.vet(2).unwrap()).unwrap();
+        assert_eq!(r.snap_inward(s).unwrap().untrusted(), 3..3);
+        let all = s.as_range().simple();
+        assert_eq!(&s[all.snap_inward(s).unwrap()], "aéöb");
+    });
+}
+
+#[test]
+fn snap_inward_without_boundary() {
+    scope("aé", |s| {
+        let r = s.vet(1u32..3).unwrap().simple();
+        let (_, r) = r.split_at(r.vet(2).unwrap()).unwrap();
+        let (r, _) = r.split_at(r.vet(2).unwrap()).unwrap();
+        // 2..2 is inside é, so there is no boundary to snap to within it
+        assert_eq!(r.untrusted(), 2..2);
+        assert_eq!(r.snap_inward(s), None);
+    });
+}
@@ -0,0 +1,66 @@
+use windex::scope;
+
+const INPUTS: &[&str] = &[
+    "",
+    "a",
+    "\n",
+    "\n\n",
+    "a\n",
+    "a\nb",
+    "a\n\nb\n",
+    "a\r\nb\r\n",
+    "a\r\n\r\nb",
+    "a\rb",
+    "a\r",
+    "\r",
+    "\r\r\n",
+    "é\r\nö\nü",
+];
+
+fn is_newline(c: &windex::Character) -> bool {
+    c.as_char() == '\n'
+}
+
+#[test]
+fn lines_matches_std() {
+    for &input in INPUTS {
+        scope(input, |s| {
+            let lines: Vec<&str> = s.lines().map(|r| &s[r]).collect();
+            let expected: Vec<&str> = input.lines().collect();
+            assert_eq!(lines, expected, "lines of {:?}", input);
+        });
+    }
+}
+
+#[test]
+fn split_by_matches_std() {
+    for &input in INPUTS {
+        scope(input, |s| {
+            let fields: Vec<&str> = s.split_by(is_newline).map(|r| &s[r]).collect();
+            let expected: Vec<&str> = input.split('\n').collect();
+            assert_eq!(fields, expected, "split of {:?}", input);
+        });
+    }
+}
+
+#[test]
+fn rsplit_by_matches_std() {
+    for &input in INPUTS {
+        scope(input, |s| {
+            let fields: Vec<&str> = s.rsplit_by(is_newline).map(|r| &s[r]).collect();
+            let expected: Vec<&str> = input.rsplit('\n').collect();
+            assert_eq!(fields, expected, "rsplit of {:?}", input);
+        });
+    }
+}
+
+#[test]
+fn split_inclusive_matches_std() {
+    for &input in INPUTS {
+        scope(input, |s| {
+            let fields: Vec<&str> = s.split_inclusive(is_newline).map(|r| &s[r]).collect();
+            let expected: Vec<&str> = input.split_inclusive('\n').collect();
+            assert_eq!(fields, expected, "split_inclusive of {:?}", input);
+        });
+    }
+}
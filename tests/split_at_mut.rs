@@ -0,0 +1,47 @@
+use windex::scope_val;
+
+#[test]
+fn split_at_start() {
+    let mut v = [1, 2, 3];
+    scope_val(&mut v[..], |mut s| {
+        let at = s.start();
+        s.split_at_mut(at, |left, right| {
+            assert!(left.is_empty());
+            assert_eq!(right.len(), 3);
+            let ix = right.vet(0u32).unwrap();
+            right[ix] = 10;
+        });
+    });
+    assert_eq!(v, [10, 2, 3]);
+}
+
+#[test]
+fn split_at_end() {
+    let mut v = [1, 2, 3];
+    scope_val(&mut v[..], |mut s| {
+        let at = s.end();
+        s.split_at_mut(at, |left, right| {
+            assert_eq!(left.len(), 3);
+            assert!(right.is_empty());
+            let ix = left.vet(2u32).unwrap();
+            left[ix] = 30;
+        });
+    });
+    assert_eq!(v, [1, 2, 30]);
+}
+
+#[test]
+fn split_str_at_char_boundary() {
+    let mut s = String::from("héllo");
+    scope_val(&mut s[..], |mut s| {
+        let at = s.vet(3u32).unwrap();
+        s.split_at_mut(at, |left, right| {
+            assert_eq!(left.as_slice(), "hé");
+            assert_eq!(right.as_slice(), "llo");
+            let ix = left.vet(1u32).unwrap();
+            assert_eq!(left[ix].as_char(), 'é');
+            right.as_slice_mut().make_ascii_uppercase();
+        });
+    });
+    assert_eq!(s, "héLLO");
+}
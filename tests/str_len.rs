@@ -0,0 +1,10 @@
+use windex::scope;
+
+#[test]
+fn byte_and_char_len() {
+    scope("héllo", |s| {
+        assert_eq!(s.byte_len(), 6);
+        assert_eq!(s.char_len(), 5);
+    });
+    scope("", |s| assert_eq!(s.char_len(), 0));
+}
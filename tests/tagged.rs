@@ -0,0 +1,11 @@
+use windex::scope;
+
+enum Bytes {}
+
+#[test]
+fn tagged_simple_index() {
+    scope(&[1, 2, 3][..], |s| {
+        let ix = s.vet(1u32).unwrap().simple().tag::<Bytes>();
+        assert_eq!(s.tagged::<Bytes>()[ix], 2);
+    });
+}